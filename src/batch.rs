@@ -0,0 +1,241 @@
+use crate::error::{Result, ResumeError};
+use crate::experience::ExperienceReport;
+use crate::fit::fit_to_one_page;
+use crate::gap::GapReport;
+use crate::resume::CV;
+use crate::scorer::Scorer;
+use crate::word_cloud::{list_files, WordCloud};
+use crate::{write_resume, OutputFormat};
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    sync::Mutex,
+    thread,
+};
+
+// How one job description in a batch went
+pub struct BatchEntry {
+    // File name of the job description without its extension
+    posting: String,
+    output_dir: String,
+    // Total point value of the tailored resume and the percentage of keywords it covers
+    result: Result<(f32, f32)>,
+}
+
+impl BatchEntry {
+    pub fn get_posting(&self) -> &String {
+        &self.posting
+    }
+
+    pub fn get_output_dir(&self) -> &String {
+        &self.output_dir
+    }
+
+    pub fn get_score(&self) -> Option<f32> {
+        self.result.as_ref().ok().map(|(score, _)| *score)
+    }
+
+    pub fn get_coverage(&self) -> Option<f32> {
+        self.result.as_ref().ok().map(|(_, coverage)| *coverage)
+    }
+
+    pub fn get_error(&self) -> Option<&ResumeError> {
+        self.result.as_ref().err()
+    }
+}
+
+// Every job description in a batch, best match first and failed ones last
+pub struct BatchReport {
+    entries: Vec<BatchEntry>,
+}
+
+impl BatchReport {
+    pub fn get_entries(&self) -> &Vec<BatchEntry> {
+        &self.entries
+    }
+
+    pub fn failed(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.result.is_err())
+            .count()
+    }
+}
+
+impl Display for BatchReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .entries
+            .iter()
+            .map(|entry| entry.posting.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("Posting".len());
+
+        writeln!(
+            f,
+            "{:<width$}  {:>8}  {:>8}  Output",
+            "Posting", "Score", "Coverage"
+        )?;
+        for entry in self.entries.iter() {
+            match &entry.result {
+                Ok((score, coverage)) => writeln!(
+                    f,
+                    "{:<width$}  {:>8.3}  {:>7.1}%  {}",
+                    entry.posting, score, coverage, entry.output_dir
+                )?,
+                Err(error) => writeln!(f, "{:<width$}  error: {}", entry.posting, error)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Tailors the CV to every job description in a directory in parallel.
+//
+// Each posting gets its own copy of the word cloud, which should be empty but
+// set up with the tokenizer, phrases and corpus to use, and its resumes are
+// written to a directory named after the posting inside the output directory,
+// along with the experience requirements the CV meets and misses.
+// A posting that fails does not stop the others, its error is in the report
+pub fn tailor_batch(
+    cv: &CV,
+    word_cloud: &WordCloud,
+    job_dir: String,
+    out_dir: String,
+    formats: &[OutputFormat],
+    one_page: bool,
+    scorer: &(impl Scorer + Sync),
+) -> Result<BatchReport> {
+    let jobs = list_files(job_dir)?;
+    let postings: Vec<(&PathBuf, String)> = jobs.iter().zip(posting_names(&jobs)).collect();
+
+    let next = AtomicUsize::new(0);
+    let entries = Mutex::new(Vec::new());
+    let workers = thread::available_parallelism()
+        .map_or(1, |count| count.get())
+        .min(jobs.len());
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            // Workers take the next posting until there are none left
+            scope.spawn(|| {
+                while let Some((job, posting)) = postings.get(next.fetch_add(1, Ordering::Relaxed))
+                {
+                    let posting = posting.clone();
+                    let output_dir = Path::new(&out_dir)
+                        .join(&posting)
+                        .to_string_lossy()
+                        .to_string();
+                    let result =
+                        tailor_posting(cv, word_cloud, job, &output_dir, formats, one_page, scorer);
+
+                    entries.lock().unwrap().push(BatchEntry {
+                        posting,
+                        output_dir,
+                        result,
+                    });
+                }
+            });
+        }
+    });
+
+    // Best score first, postings that tie or failed stay in name order
+    let mut entries = entries.into_inner().unwrap();
+    entries.sort_by(|a, b| a.posting.cmp(&b.posting));
+    entries.sort_by(|a, b| {
+        let score = |entry: &BatchEntry| entry.get_score().unwrap_or(f32::NEG_INFINITY);
+        score(b).total_cmp(&score(a))
+    });
+
+    Ok(BatchReport { entries })
+}
+
+// Names postings after their file without the extension, postings that would
+// share a name, like "acme.txt" and "acme.html", keep their extension instead
+fn posting_names(jobs: &[PathBuf]) -> Vec<String> {
+    let stem = |job: &PathBuf| {
+        job.file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string()
+    };
+
+    jobs.iter()
+        .map(|job| {
+            if jobs.iter().filter(|other| stem(other) == stem(job)).count() > 1 {
+                job.file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string()
+            } else {
+                stem(job)
+            }
+        })
+        .collect()
+}
+
+// Tailors and writes the resume for one posting, returning its score and keyword coverage
+fn tailor_posting(
+    cv: &CV,
+    word_cloud: &WordCloud,
+    job: &Path,
+    output_dir: &str,
+    formats: &[OutputFormat],
+    one_page: bool,
+    scorer: &impl Scorer,
+) -> Result<(f32, f32)> {
+    let mut word_cloud = word_cloud.clone();
+    word_cloud.add_text_file(job.to_string_lossy().to_string())?;
+
+    let mut resume = cv.generate_resume_with_scorer(&word_cloud, scorer);
+    let fit = if one_page {
+        Some(fit_to_one_page(&mut resume, &word_cloud, scorer)?)
+    } else {
+        None
+    };
+
+    fs::create_dir_all(output_dir).map_err(|error| ResumeError::io(output_dir, error))?;
+    for format in formats {
+        let output_file = Path::new(output_dir).join(format!("resume.{}", format.extension()));
+        write_resume(&resume, *format, output_file.to_string_lossy().to_string())?;
+    }
+
+    let requirements = ExperienceReport::from_job_file(
+        cv,
+        job.to_string_lossy().to_string(),
+        word_cloud.get_tokenizer(),
+    )?;
+    if !requirements.is_empty() {
+        let output_file = Path::new(output_dir).join("requirements.txt");
+        fs::write(&output_file, requirements.to_string())
+            .map_err(|error| ResumeError::io(output_file.to_string_lossy(), error))?;
+    }
+
+    // The resume is written even if it does not fit, but the posting still fails
+    if let Some(fit) = fit {
+        fit.ensure_fits()?;
+    }
+
+    let coverage = GapReport::new(cv, &word_cloud).get_coverage();
+
+    Ok((resume.get_total_score(), coverage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn postings_that_share_a_name_keep_their_extension() {
+        let jobs: Vec<PathBuf> = ["jobs/acme.html", "jobs/acme.txt", "jobs/rusty.txt"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+
+        assert_eq!(posting_names(&jobs), vec!["acme.html", "acme.txt", "rusty"]);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::scorer::Scorer;
+use crate::word_cloud::WordCloud;
+use std::collections::HashMap;
+
+// Why an item scored the way it did
+#[derive(Debug, Clone, Default)]
+pub struct ScoreBreakdown {
+    // Every keyword found in the item with its weight, heaviest first
+    matched_terms: Vec<(String, f32)>,
+    // The title and description scored on their own, for most scorers these
+    // do not add up to the point value since the item is scored as a whole
+    title_score: f32,
+    description_score: f32,
+    //                 Bullet
+    //                   |       Keywords in the bullet
+    //                   V                V
+    bullet_terms: HashMap<String, Vec<(String, f32)>>,
+}
+
+impl ScoreBreakdown {
+    pub fn new<'a>(
+        word_cloud: &WordCloud,
+        scorer: &impl Scorer,
+        title: impl IntoIterator<Item = &'a String>,
+        description: impl IntoIterator<Item = &'a String>,
+    ) -> Self {
+        let title: Vec<&String> = title.into_iter().collect();
+        let description: Vec<&String> = description.into_iter().collect();
+
+        let mut bullet_terms = HashMap::new();
+        for bullet in description.iter() {
+            bullet_terms.insert(bullet.to_string(), matched_terms(word_cloud, [*bullet]));
+        }
+
+        Self {
+            matched_terms: matched_terms(
+                word_cloud,
+                title.iter().chain(description.iter()).copied(),
+            ),
+            title_score: scorer.score_all(word_cloud, title.iter().copied()),
+            description_score: scorer.score_all(word_cloud, description.iter().copied()),
+            bullet_terms,
+        }
+    }
+
+    pub fn get_matched_terms(&self) -> &Vec<(String, f32)> {
+        &self.matched_terms
+    }
+
+    pub fn get_title_score(&self) -> f32 {
+        self.title_score
+    }
+
+    pub fn get_description_score(&self) -> f32 {
+        self.description_score
+    }
+
+    // Keywords in one bullet of the description, None if the bullet was not scored
+    pub fn get_bullet_terms(&self, bullet: &str) -> Option<&Vec<(String, f32)>> {
+        self.bullet_terms.get(bullet)
+    }
+}
+
+// Every distinct keyword in the texts with its weight, heaviest first
+fn matched_terms<'a>(
+    word_cloud: &WordCloud,
+    texts: impl IntoIterator<Item = &'a String>,
+) -> Vec<(String, f32)> {
+    let mut terms: Vec<(String, f32)> = Vec::new();
+    for text in texts {
+        for (term, weight) in word_cloud.get_term_weights(text.clone()) {
+            let term = word_cloud.get_display_form(&term);
+            if weight > 0.0 && !terms.iter().any(|(matched, _)| *matched == term) {
+                terms.push((term, weight));
+            }
+        }
+    }
+    terms.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    terms
+}
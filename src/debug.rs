@@ -0,0 +1,129 @@
+use crate::error::Result;
+use crate::markdown::MarkdownRenderer;
+use crate::renderer::{capitalize, ResumeRenderer};
+use crate::resume::*;
+
+// Renders a resume as markdown annotated with why every item ranked where it did,
+// each scored bullet is followed by the keywords it matched
+pub struct DebugRenderer {
+    markdown: MarkdownRenderer,
+}
+
+impl Default for DebugRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DebugRenderer {
+    pub fn new() -> Self {
+        Self {
+            markdown: MarkdownRenderer::new(),
+        }
+    }
+}
+
+// Formats keywords like "rust (4.000), python (2.000)"
+fn format_terms(terms: &[(String, f32)]) -> String {
+    if terms.is_empty() {
+        return String::from("no keywords");
+    }
+
+    terms
+        .iter()
+        .map(|(term, weight)| format!("{} ({:.3})", term, weight))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+impl ResumeRenderer for DebugRenderer {
+    fn write_section_header(&mut self, text: &str) -> Result<()> {
+        self.markdown.write_section_header(text)
+    }
+
+    fn write_item_header(&mut self, text: &str) -> Result<()> {
+        self.markdown.write_item_header(text)
+    }
+
+    fn write_extra_info(&mut self, text: &str) -> Result<()> {
+        self.markdown.write_extra_info(text)
+    }
+
+    fn write_normal(&mut self, text: &str) -> Result<()> {
+        self.markdown.write_normal(text)
+    }
+
+    fn write_bullets(&mut self, items: &[String]) -> Result<()> {
+        self.markdown.write_bullets(items)
+    }
+
+    fn write_item_bullets<T>(
+        &mut self,
+        item: &SortableResumeItem<T>,
+        bullets: &[String],
+    ) -> Result<()> {
+        let breakdown = item.get_breakdown();
+
+        self.write_normal(&format!(
+            "Score {:.3} (title {:.3}, description {:.3}): {}",
+            item.get_point_value(),
+            breakdown.get_title_score(),
+            breakdown.get_description_score(),
+            format_terms(breakdown.get_matched_terms())
+        ))?;
+
+        // Bullets that were not scored on their own, like the coursework line, are left as they are
+        let annotated: Vec<String> = bullets
+            .iter()
+            .map(|bullet| match breakdown.get_bullet_terms(bullet) {
+                Some(terms) => format!("{} `[{}]`", bullet, format_terms(terms)),
+                None => bullet.clone(),
+            })
+            .collect();
+
+        self.write_bullets(&annotated)
+    }
+
+    // Every skill on its own line with its score and keywords
+    fn render_skills(
+        &mut self,
+        skills: &[(String, Vec<SortableResumeItem<String>>)],
+    ) -> Result<()> {
+        if skills.is_empty() {
+            return Ok(());
+        }
+
+        self.write_section_header("Skills")?;
+
+        for (category, category_skills) in skills.iter() {
+            self.write_item_header(&capitalize(category))?;
+
+            let annotated: Vec<String> = category_skills
+                .iter()
+                .map(|skill| {
+                    format!(
+                        "{} {:.3} `[{}]`",
+                        skill.sortable,
+                        skill.get_point_value(),
+                        format_terms(skill.get_breakdown().get_matched_terms())
+                    )
+                })
+                .collect();
+
+            self.write_bullets(&annotated)?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Vec<u8>> {
+        self.markdown.finish()
+    }
+}
+
+// Renders the annotated resume in memory
+pub fn render_debug_from_resume(resume: &Resume) -> Result<Vec<u8>> {
+    let mut renderer = DebugRenderer::new();
+    renderer.render_resume(resume)?;
+    renderer.finish()
+}
@@ -1,152 +1,134 @@
-use docx_rust::*;
-// use docx_rust::document::Paragraph;
-// use docx_rust::formatting::Size;
-// use docx_rust::Docx;
+use crate::error::{Result, ResumeError};
+use crate::renderer::ResumeRenderer;
 use crate::resume::*;
-use document::{Numbering, Paragraph, ParagraphContent, Run, RunContent, Tab};
-use formatting::{CharacterProperty, Fonts, Indent, IndentLevel, JustificationVal, NumberingProperty, ParagraphProperty};
-
-// Helper capitalize funciton
-fn capitalize(string: String) -> String {
-    let mut output = String::new();
-    let mut first = true;
-
-    for character in string.chars() {
-        if first {
-            output.push(character.to_ascii_uppercase());
-            first = false;
-        } else {
-            output.push(character);
-        }
-    }
-
-    output
-}
+use document::{Paragraph, Run, RunContent, Tab};
+use docx_rust::*;
+use formatting::{CharacterProperty, Fonts};
+use std::fs;
+use std::io::{Cursor, Write};
 
-pub fn generate_docx_from_resume(resume: &Resume) {
-    let mut docx = Docx::default();
+// Renders a resume as a word document
+pub struct DocxRenderer {
+    docx: Docx<'static>,
 
     // Text properties for each section
-    let section_header = CharacterProperty::default()
-        .color(0x333333)
-        .size(42isize)
-        .fonts(Fonts::default().ascii("Times New Roman"));
-
-    let item_header = CharacterProperty::default()
-        .color(0x000000)
-        .size(42isize)
-        .bold(true)
-        .fonts(Fonts::default().ascii("Times New Roman"));
-
-    let normal_text = CharacterProperty::default()
-        .color(0x000000)
-        .size(24isize)
-        .fonts(Fonts::default().ascii("Times New Roman"));
-
-    // Closures for easy writing to the docx file
-    let write_section_header = |d: &mut Docx, text: &str| {
-        d.document.push(
-            Paragraph::default().push(
-                Run::default()
-                    .property(section_header.clone())
-                    .push_text(text.to_string()),
-            ),
-        );
-    };
+    section_header: CharacterProperty<'static>,
+    item_header: CharacterProperty<'static>,
+    extra_info: CharacterProperty<'static>,
+    normal_text: CharacterProperty<'static>,
+}
 
-    let write_item_header = |d: &mut Docx, text: &str| {
-        d.document.push(
-            Paragraph::default().push(
-                Run::default()
-                    .property(item_header.clone())
-                    .push_text(text.to_string()),
-            ),
-        );
-    };
+impl Default for DocxRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DocxRenderer {
+    pub fn new() -> Self {
+        Self {
+            docx: Docx::default(),
+            section_header: CharacterProperty::default()
+                .color(0x333333)
+                .size(42isize)
+                .fonts(Fonts::default().ascii("Times New Roman")),
+            item_header: CharacterProperty::default()
+                .color(0x000000)
+                .size(42isize)
+                .bold(true)
+                .fonts(Fonts::default().ascii("Times New Roman")),
+            extra_info: CharacterProperty::default()
+                .color(0x000000)
+                .size(24isize)
+                .italics(true)
+                .fonts(Fonts::default().ascii("Times New Roman")),
+            normal_text: CharacterProperty::default()
+                .color(0x000000)
+                .size(24isize)
+                .fonts(Fonts::default().ascii("Times New Roman")),
+        }
+    }
 
-    let write_normal = |d: &mut Docx, text: &str| {
-        d.document.push(
+    fn write_paragraph(&mut self, property: CharacterProperty<'static>, text: &str) {
+        self.docx.document.push(
             Paragraph::default().push(
                 Run::default()
-                    .property(normal_text.clone())
+                    .property(property)
                     .push_text(text.to_string()),
             ),
         );
-    };
+    }
+}
 
+impl ResumeRenderer for DocxRenderer {
+    fn write_section_header(&mut self, text: &str) -> Result<()> {
+        self.write_paragraph(self.section_header.clone(), text);
+        Ok(())
+    }
 
-    //TODO: Find a way to make unordered lists work to make document parsing much easier
-    let write_bullets = |d: &mut Docx, text: &Vec<String>| {
-        let mut indent = Indent::default();
-        indent.left = Some(42isize);
-        for item in text {
-            d.document.push(
-                Paragraph::default()
-                    .push(
-                        Run::default()
-                            .push(RunContent::Tab(Tab::default()))
-                            .push_text(format!("• {}", item))
-                    )
-            );
-        }
-    };
+    fn write_item_header(&mut self, text: &str) -> Result<()> {
+        self.write_paragraph(self.item_header.clone(), text);
+        Ok(())
+    }
 
-    // Skills heading
-    write_section_header(&mut docx, "Skills");
+    fn write_extra_info(&mut self, text: &str) -> Result<()> {
+        self.write_paragraph(self.extra_info.clone(), text);
+        Ok(())
+    }
 
-    // Add skills
-    for category in resume.get_skills().iter() {
-        write_item_header(&mut docx, &capitalize(category.0.clone()));
+    fn write_normal(&mut self, text: &str) -> Result<()> {
+        self.write_paragraph(self.normal_text.clone(), text);
+        Ok(())
+    }
 
-        let mut skills = String::new();
-        for item in category.1.iter() {
-            skills.push_str(&std::format!("{} ", item.sortable));
+    //TODO: Find a way to make unordered lists work to make document parsing much easier
+    fn write_bullets(&mut self, items: &[String]) -> Result<()> {
+        for item in items {
+            self.docx.document.push(
+                Paragraph::default().push(
+                    Run::default()
+                        .property(self.normal_text.clone())
+                        .push(RunContent::Tab(Tab))
+                        .push_text(format!("• {}", item)),
+                ),
+            );
         }
-
-        write_normal(&mut docx, &skills);
+        Ok(())
     }
 
-    // Add Work experience
-    write_section_header(&mut docx, "Work Experience");
-
-    for experience in resume.get_work_experience().iter() {
-        let e = &experience.sortable;
+    fn finish(self) -> Result<Vec<u8>> {
+        // Writing borrows the document for its whole lifetime so shorten it to this function
+        let mut docx: Docx = self.docx;
 
-        write_item_header(
-            &mut docx,
-            &format!("{} {}", e.get_job_title(), {
-                let mut o = String::new();
+        // The docx archive needs a seekable writer
+        let contents = docx
+            .write(Cursor::new(Vec::new()))
+            .map_err(|error| ResumeError::render("docx", format!("{:?}", error)))?;
 
-                let start_date = e.get_job_start();
-                let end_date = e.get_job_end();
+        Ok(contents.into_inner())
+    }
+}
 
-                o.push_str(&format!("{}", start_date));
-                match end_date {
-                    Some(date) => o.push_str(&format!("-{}", date)),
-                    None => {}
-                }
+// Writes the docx resume to a file
+pub fn generate_docx_from_resume(resume: &Resume, output_file: String) -> Result<()> {
+    let contents = render_docx_from_resume(resume)?;
 
-                o
-            }),
-        );
+    fs::write(&output_file, contents).map_err(|error| ResumeError::io(output_file, error))
+}
 
-        write_normal(
-            &mut docx,
-            &format!("{} {}", e.get_company_name(), {
-                let mut o = String::new();
-                match e.get_job_location() {
-                    Some(location) => o = format!("{}", location),
-                    None => {}
-                }
-                o
-            }),
-        );
+// Writes the docx resume to any writer
+pub fn write_docx_from_resume<W: Write>(resume: &Resume, writer: &mut W) -> Result<()> {
+    let contents = render_docx_from_resume(resume)?;
 
-        write_bullets(
-            &mut docx,
-            e.get_job_description()
-        );
-    }
+    // The writer has no file name, but it is still an io error so it keeps its source
+    writer
+        .write_all(&contents)
+        .map_err(|error| ResumeError::io("<writer>", error))
+}
 
-    docx.write_file("./res/test.docx").unwrap();
+// Renders the docx resume in memory
+pub fn render_docx_from_resume(resume: &Resume) -> Result<Vec<u8>> {
+    let mut renderer = DocxRenderer::new();
+    renderer.render_resume(resume)?;
+    renderer.finish()
 }
@@ -0,0 +1,132 @@
+use std::{error::Error, fmt::Display, io};
+
+// Errors that can happen while loading, saving or rendering a resume
+#[derive(Debug)]
+pub enum ResumeError {
+    // Reading or writing a file failed
+    Io {
+        filename: String,
+        source: io::Error,
+    },
+    // A json file could not be (de)serialized, json_path is where in the document it failed
+    Parse {
+        filename: String,
+        json_path: String,
+        source: serde_json::Error,
+    },
+    // A csv file like a skills taxonomy could not be read
+    Csv {
+        filename: String,
+        source: csv::Error,
+    },
+    // Text could not be extracted from a job description, like a damaged pdf
+    Extract {
+        filename: String,
+        message: String,
+    },
+    // A font could not be loaded or is not a valid font
+    Font {
+        filename: String,
+        message: String,
+    },
+    // Text could not be laid out on the page
+    Layout {
+        text: String,
+        message: String,
+    },
+    // A renderer failed to produce its output
+    Render {
+        format: String,
+        message: String,
+    },
+    // Some postings of a batch failed, each one's error is in the batch report
+    Batch {
+        failed: usize,
+        total: usize,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, ResumeError>;
+
+impl ResumeError {
+    pub fn io(filename: impl Into<String>, source: io::Error) -> Self {
+        ResumeError::Io {
+            filename: filename.into(),
+            source,
+        }
+    }
+
+    pub fn extract(filename: impl Into<String>, message: impl Display) -> Self {
+        ResumeError::Extract {
+            filename: filename.into(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn font(filename: impl Into<String>, message: impl Display) -> Self {
+        ResumeError::Font {
+            filename: filename.into(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn layout(text: impl Into<String>, message: impl Display) -> Self {
+        ResumeError::Layout {
+            text: text.into(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn render(format: impl Into<String>, message: impl Display) -> Self {
+        ResumeError::Render {
+            format: format.into(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for ResumeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResumeError::Io { filename, source } => write!(f, "{}: {}", filename, source),
+            ResumeError::Parse {
+                filename,
+                json_path,
+                source,
+            } => write!(
+                f,
+                "{}: invalid json at `{}`: {}",
+                filename, json_path, source
+            ),
+            ResumeError::Csv { filename, source } => {
+                write!(f, "{}: invalid csv: {}", filename, source)
+            }
+            ResumeError::Extract { filename, message } => {
+                write!(f, "{}: unable to extract text: {}", filename, message)
+            }
+            ResumeError::Font { filename, message } => {
+                write!(f, "{}: unable to load font: {}", filename, message)
+            }
+            ResumeError::Layout { text, message } => {
+                write!(f, "unable to lay out \"{}\": {}", text, message)
+            }
+            ResumeError::Render { format, message } => {
+                write!(f, "unable to render {}: {}", format, message)
+            }
+            ResumeError::Batch { failed, total } => {
+                write!(f, "{} of {} postings failed", failed, total)
+            }
+        }
+    }
+}
+
+impl Error for ResumeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ResumeError::Io { source, .. } => Some(source),
+            ResumeError::Parse { source, .. } => Some(source),
+            ResumeError::Csv { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,460 @@
+use crate::error::Result;
+use crate::job_description::load_job_description;
+use crate::resume::{Date, WorkExperience, CV};
+use crate::tokenizer::Tokenizer;
+use chrono::Datelike;
+use std::{collections::HashSet, fmt::Display};
+
+// Words between "years" and the skill that say nothing about the skill itself,
+// like "5+ years of professional experience with Rust"
+const FILLER_WORDS: [&str; 21] = [
+    "of",
+    "in",
+    "with",
+    "using",
+    "on",
+    "for",
+    "the",
+    "a",
+    "an",
+    "as",
+    "experience",
+    "experienced",
+    "professional",
+    "relevant",
+    "industry",
+    "hands-on",
+    "commercial",
+    "practical",
+    "proven",
+    "working",
+    "work",
+];
+
+// Words that end the skill, what follows is usually a second requirement
+const SKILL_ENDINGS: [&str; 3] = ["and", "or", "including"];
+
+// Spelled out numbers that postings use for years
+const NUMBER_WORDS: [&str; 11] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+];
+
+// A minimum amount of experience a job description asks for, like "3+ years of C++"
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExperienceRequirement {
+    skill: String,
+    min_years: f32,
+}
+
+impl ExperienceRequirement {
+    pub fn new(skill: String, min_years: f32) -> Self {
+        Self { skill, min_years }
+    }
+
+    pub fn get_skill(&self) -> &String {
+        &self.skill
+    }
+
+    pub fn get_min_years(&self) -> f32 {
+        self.min_years
+    }
+
+    // Finds every "<number> years <skill>" in the text. A range like "3-5 years" asks
+    // for its lower bound, a list like "Rust, Python or Go" asks for each skill in it,
+    // and a skill asked for twice keeps the larger minimum
+    pub fn extract(text: &str) -> Vec<Self> {
+        let mut requirements: Vec<Self> = Vec::new();
+
+        let clauses = text.split(['\n', ';', '•', '(', ')', '!', '?']);
+        for clause in clauses.flat_map(|clause| clause.split(". ")) {
+            let words: Vec<&str> = clause.split_whitespace().collect();
+
+            for (i, word) in words.iter().enumerate() {
+                if !is_years(word) {
+                    continue;
+                }
+
+                // Only the word right before "years" is read and "200," is not a number,
+                // so a count from an earlier part of the sentence is not taken for the years
+                let Some(min_years) = min_years_before(&words[..i]) else {
+                    continue;
+                };
+
+                for skill in skills_after(&words[i + 1..]) {
+                    match requirements.iter_mut().find(|requirement| {
+                        requirement.skill.to_lowercase() == skill.to_lowercase()
+                    }) {
+                        Some(requirement) => {
+                            requirement.min_years = requirement.min_years.max(min_years)
+                        }
+                        None => requirements.push(Self::new(skill, min_years)),
+                    }
+                }
+            }
+        }
+
+        requirements
+    }
+}
+
+impl Display for ExperienceRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}+ years of {}", self.min_years, self.skill)
+    }
+}
+
+fn is_years(word: &str) -> bool {
+    let word = word
+        .trim_end_matches(|c: char| !c.is_alphabetic())
+        .to_lowercase();
+    matches!(word.as_str(), "year" | "years" | "yr" | "yrs")
+}
+
+// Reads the number in front of "years", skipping "+" and "or more"
+fn min_years_before(words: &[&str]) -> Option<f32> {
+    let word = words
+        .iter()
+        .rev()
+        .find(|word| !matches!(word.to_lowercase().as_str(), "+" | "or" | "more" | "plus"))?;
+
+    // "3+", "3-5" and "(5)" all start with the minimum
+    let number = word
+        .trim_start_matches(|c: char| !c.is_alphanumeric())
+        .split(['-', '–', '+', ')'])
+        .next()?
+        .to_lowercase();
+
+    number.parse::<f32>().ok().or_else(|| {
+        NUMBER_WORDS
+            .iter()
+            .position(|name| *name == number)
+            .map(|n| n as f32)
+    })
+}
+
+// The skill after "years", followed by the rest of the list when the skill starts one
+// like "Rust, Python or Go". The list ends at its "and" or "or" item, or at the first
+// part that does not look like a skill name, like "preferably in Java"
+fn skills_after(words: &[&str]) -> Vec<String> {
+    let mut parts = words
+        .split_inclusive(|word| word.ends_with(','))
+        .map(|part| {
+            part.iter()
+                .map(|word| word.trim_end_matches(','))
+                .collect::<Vec<&str>>()
+        });
+
+    let Some(first) = parts.next() else {
+        return Vec::new();
+    };
+    let Some(skill) = skill_after(&first) else {
+        return Vec::new();
+    };
+    let mut skills = vec![skill];
+
+    // A skill that stopped at "and" or "or" is not the start of a list
+    if first.iter().any(|word| is_skill_ending(word)) {
+        return skills;
+    }
+
+    for part in parts {
+        let (part, last) = match part.split_first() {
+            Some((first, rest)) if is_skill_ending(first) => (rest, true),
+            _ => (&part[..], false),
+        };
+
+        let items: Vec<&[&str]> = part.split(|word| is_skill_ending(word)).collect();
+        for item in items.iter() {
+            let is_name = (1..=3).contains(&item.len())
+                && !item
+                    .iter()
+                    .any(|word| FILLER_WORDS.contains(&word.to_lowercase().as_str()));
+            if !is_name {
+                return skills;
+            }
+
+            skills.push(item.join(" ").trim_end_matches(['.', ':']).to_string());
+        }
+
+        if last || items.len() > 1 {
+            break;
+        }
+    }
+
+    skills
+}
+
+fn is_skill_ending(word: &str) -> bool {
+    SKILL_ENDINGS.contains(&word.to_lowercase().as_str())
+}
+
+// The skill is what follows "years" once the filler words are gone, a leading verb
+// like "building" or "programming" is dropped too as long as something is left.
+// The skill has to be tied to the years by a filler word, a verb or a trailing
+// "experience", so "founded 20 years ago" is not a requirement
+fn skill_after(words: &[&str]) -> Option<String> {
+    let end = words
+        .iter()
+        .position(|word| is_skill_ending(word))
+        .unwrap_or(words.len());
+    let mut words = &words[..end];
+
+    let is_filler = |word: &&str| {
+        let word = word
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        FILLER_WORDS.contains(&word.as_str())
+    };
+    let connected = match words {
+        [first, ..] if is_filler(first) || first.ends_with("ing") => true,
+        [.., last] => is_filler(last),
+        [] => false,
+    };
+    if !connected {
+        return None;
+    }
+
+    loop {
+        match words {
+            [first, rest @ ..] if is_filler(first) => words = rest,
+            [first, rest @ ..] if first.ends_with("ing") && !rest.iter().all(is_filler) => {
+                words = rest
+            }
+            [rest @ .., last] if is_filler(last) => words = rest,
+            _ => break,
+        }
+    }
+
+    let skill = words.join(" ");
+    let skill = skill.trim_end_matches(['.', ':']);
+    if skill.is_empty() {
+        None
+    } else {
+        Some(skill.to_string())
+    }
+}
+
+// How one requirement compares with the work experience in the CV
+pub struct RequirementCheck {
+    requirement: ExperienceRequirement,
+    years: f32,
+    // Job titles of the work experience that mentions the skill
+    experiences: Vec<String>,
+}
+
+impl RequirementCheck {
+    pub fn get_requirement(&self) -> &ExperienceRequirement {
+        &self.requirement
+    }
+
+    pub fn get_years(&self) -> f32 {
+        self.years
+    }
+
+    pub fn get_experiences(&self) -> &Vec<String> {
+        &self.experiences
+    }
+
+    pub fn is_met(&self) -> bool {
+        self.years >= self.requirement.min_years
+    }
+}
+
+// Which experience requirements of a job description the CV meets
+pub struct ExperienceReport {
+    checks: Vec<RequirementCheck>,
+}
+
+impl ExperienceReport {
+    // Adds up the time spent in every job whose title or description mentions each
+    // skill, jobs that overlap only count once. Text is compared with the tokenizer
+    // so stemming and stopwords work the same as in the word cloud, but the skill
+    // names are never stemmed
+    pub fn new(cv: &CV, requirements: &[ExperienceRequirement], tokenizer: &Tokenizer) -> Self {
+        let mut tokenizer = tokenizer.clone();
+        tokenizer.keep_names(cv.get_skills().get_skill_tree().values().flatten());
+        tokenizer.keep_names(requirements.iter().map(ExperienceRequirement::get_skill));
+
+        let experience_terms: Vec<(&WorkExperience, HashSet<String>)> = cv
+            .get_work_experience()
+            .iter()
+            .map(|experience| {
+                let texts = std::iter::once(experience.get_job_title())
+                    .chain(experience.get_job_description());
+                let terms = texts.flat_map(|text| tokenizer.tokenize(text)).collect();
+                (experience, terms)
+            })
+            .collect();
+
+        let checks = requirements
+            .iter()
+            .map(|requirement| {
+                let skill_terms = tokenizer.tokenize(requirement.get_skill());
+                let matching: Vec<&WorkExperience> = experience_terms
+                    .iter()
+                    .filter(|(_, terms)| {
+                        !skill_terms.is_empty()
+                            && skill_terms.iter().all(|term| terms.contains(term))
+                    })
+                    .map(|(experience, _)| *experience)
+                    .collect();
+
+                RequirementCheck {
+                    requirement: requirement.clone(),
+                    years: total_years(&matching),
+                    experiences: matching
+                        .iter()
+                        .map(|experience| experience.get_job_title().clone())
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Self { checks }
+    }
+
+    // Extracts the requirements from a job description file and checks them
+    pub fn from_job_file(cv: &CV, filename: String, tokenizer: &Tokenizer) -> Result<Self> {
+        let text = load_job_description(filename)?;
+        Ok(Self::new(
+            cv,
+            &ExperienceRequirement::extract(&text),
+            tokenizer,
+        ))
+    }
+
+    pub fn get_checks(&self) -> &Vec<RequirementCheck> {
+        &self.checks
+    }
+
+    pub fn met(&self) -> usize {
+        self.checks.iter().filter(|check| check.is_met()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+}
+
+impl Display for ExperienceReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Experience requirements met: {} of {}",
+            self.met(),
+            self.checks.len()
+        )?;
+
+        for check in self.checks.iter() {
+            let status = if check.is_met() { "met" } else { "unmet" };
+            write!(
+                f,
+                "    {:<5}  {}: {:.1} years",
+                status, check.requirement, check.years
+            )?;
+            if !check.experiences.is_empty() {
+                write!(f, " ({})", check.experiences.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Months since year 0, a date without a month counts from the start of the year
+// or, when it ends a job, to the end of it
+fn month_index(date: &Date, end: bool) -> i32 {
+    let month = date.get_month().unwrap_or(if end { 12 } else { 1 });
+    date.get_year() * 12 + month as i32 - 1
+}
+
+// Years worked across the experiences, merging the months where they overlap.
+// Both the first and the last month of a job count and an ongoing job runs until now
+fn total_years(experiences: &[&WorkExperience]) -> f32 {
+    let today = chrono::Local::now().date_naive();
+    let now = today.year() * 12 + today.month() as i32 - 1;
+
+    let mut ranges: Vec<(i32, i32)> = experiences
+        .iter()
+        .map(|experience| {
+            let start = month_index(experience.get_job_start(), false);
+            let end = experience
+                .get_job_end()
+                .as_ref()
+                .map_or(now, |end| month_index(end, true));
+            (start, end + 1)
+        })
+        .filter(|(start, end)| start < end)
+        .collect();
+    ranges.sort();
+
+    let mut months = 0;
+    let mut counted_until = i32::MIN;
+    for (start, end) in ranges {
+        let start = start.max(counted_until);
+        if end > start {
+            months += end - start;
+            counted_until = end;
+        }
+    }
+
+    months as f32 / 12.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract(text: &str) -> Vec<(String, f32)> {
+        ExperienceRequirement::extract(text)
+            .into_iter()
+            .map(|requirement| (requirement.skill, requirement.min_years))
+            .collect()
+    }
+
+    #[test]
+    fn extracts_skills_and_minimum_years() {
+        let text = "3+ years of C++ and strong communication skills\n\
+                    5 years building video software\n\
+                    At least two years of professional experience with Rust, Python or Go\n\
+                    3-5 yrs C++ experience.";
+
+        assert_eq!(
+            extract(text),
+            vec![
+                ("C++".to_string(), 3.0),
+                ("video software".to_string(), 5.0),
+                ("Rust".to_string(), 2.0),
+                ("Python".to_string(), 2.0),
+                ("Go".to_string(), 2.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn lists_end_at_their_last_item() {
+        assert_eq!(
+            extract("4 years with Kafka, Spark and Flink, plus a degree"),
+            vec![
+                ("Kafka".to_string(), 4.0),
+                ("Spark".to_string(), 4.0),
+                ("Flink".to_string(), 4.0),
+            ]
+        );
+        assert_eq!(
+            extract("3+ years of Java, preferably in fintech"),
+            vec![("Java".to_string(), 3.0)]
+        );
+        assert_eq!(
+            extract("We have 200 engineers, years of experience in Rust"),
+            Vec::<(String, f32)>::new()
+        );
+    }
+
+    #[test]
+    fn ignores_years_that_are_not_requirements() {
+        assert!(extract("Founded 20 years ago, we have grown for 5 years straight.").is_empty());
+        assert!(extract("Our team is 10 years old").is_empty());
+    }
+}
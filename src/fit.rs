@@ -0,0 +1,300 @@
+use crate::error::{Result, ResumeError};
+use crate::pdf::{count_pdf_pages, PdfFonts};
+use crate::resume::*;
+use crate::scorer::Scorer;
+use crate::word_cloud::WordCloud;
+use std::fmt::Display;
+
+// Something that was removed from the resume to make it fit on one page
+pub enum DroppedItem {
+    Bullet { item: String, text: String },
+    Coursework { school: String, course: String },
+    Project { name: String },
+}
+
+impl Display for DroppedItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DroppedItem::Bullet { item, text } => write!(f, "Bullet from {}: {}", item, text),
+            DroppedItem::Coursework { school, course } => {
+                write!(f, "Coursework from {}: {}", school, course)
+            }
+            DroppedItem::Project { name } => write!(f, "Project: {}", name),
+        }
+    }
+}
+
+// What fitting the resume to one page did, in the order things were dropped
+pub struct FitReport {
+    dropped: Vec<DroppedItem>,
+    page_count: usize,
+}
+
+impl FitReport {
+    pub fn get_dropped(&self) -> &Vec<DroppedItem> {
+        &self.dropped
+    }
+
+    // Number of pages the resume takes after fitting, more than one if
+    // there was nothing left to drop
+    pub fn get_page_count(&self) -> usize {
+        self.page_count
+    }
+
+    pub fn fits(&self) -> bool {
+        self.page_count <= 1
+    }
+
+    // An error if the resume still takes more than one page, so callers can
+    // write the resume and still fail
+    pub fn ensure_fits(&self) -> Result<()> {
+        if self.fits() {
+            Ok(())
+        } else {
+            Err(ResumeError::layout(
+                "resume",
+                format!(
+                    "still takes {} pages with nothing left to drop",
+                    self.page_count
+                ),
+            ))
+        }
+    }
+}
+
+impl Display for FitReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.dropped.is_empty() {
+            writeln!(f, "Nothing was dropped")?;
+        } else {
+            writeln!(f, "Dropped to fit on one page:")?;
+            for item in self.dropped.iter() {
+                writeln!(f, "    {}", item)?;
+            }
+        }
+
+        if !self.fits() {
+            writeln!(f, "Resume still takes {} pages", self.page_count)?;
+        }
+
+        Ok(())
+    }
+}
+
+// The parts of a resume that can be dropped, pointing at where they are
+enum Candidate {
+    JobBullet(usize, usize),
+    ProjectBullet(usize, usize),
+    Coursework(usize, usize),
+    Project(usize),
+}
+
+// Finds the least relevant thing left in the resume.
+// Every work experience and project keeps at least one bullet, a project
+// can only be dropped once it is down to its last bullet
+fn least_relevant(
+    resume: &Resume,
+    word_cloud: &WordCloud,
+    scorer: &impl Scorer,
+) -> Option<Candidate> {
+    let mut candidates: Vec<(f32, Candidate)> = Vec::new();
+    let score = |text: &String| scorer.score(word_cloud, text);
+
+    for (i, experience) in resume.get_work_experience().iter().enumerate() {
+        let bullets = experience.sortable.get_job_description();
+        if bullets.len() > 1 {
+            for (j, bullet) in bullets.iter().enumerate() {
+                candidates.push((score(bullet), Candidate::JobBullet(i, j)));
+            }
+        }
+    }
+
+    for (i, education) in resume.get_education().iter().enumerate() {
+        for (j, course) in education.sortable.get_coursework().iter().enumerate() {
+            candidates.push((score(course), Candidate::Coursework(i, j)));
+        }
+    }
+
+    for (i, project) in resume.get_projects().iter().enumerate() {
+        let bullets = project.sortable.get_project_description();
+        if bullets.len() > 1 {
+            for (j, bullet) in bullets.iter().enumerate() {
+                candidates.push((score(bullet), Candidate::ProjectBullet(i, j)));
+            }
+        } else {
+            // The point value was computed from all of the original bullets,
+            // so the project is scored on what is left of it
+            candidates.push((scorer.score_all(word_cloud, bullets), Candidate::Project(i)));
+        }
+    }
+
+    // On a tie drop whatever comes last in the resume
+    candidates
+        .into_iter()
+        .rev()
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, candidate)| candidate)
+}
+
+// Removes the candidate from the resume and describes what was removed
+fn drop_candidate(resume: &mut Resume, candidate: Candidate) -> DroppedItem {
+    match candidate {
+        Candidate::JobBullet(i, j) => {
+            let experience = &mut resume.get_work_experience_mut()[i].sortable;
+            DroppedItem::Bullet {
+                item: experience.get_job_title().clone(),
+                text: experience.remove_job_description(j),
+            }
+        }
+        Candidate::ProjectBullet(i, j) => {
+            let project = &mut resume.get_projects_mut()[i].sortable;
+            DroppedItem::Bullet {
+                item: project.get_project_name().clone(),
+                text: project.remove_project_description(j),
+            }
+        }
+        Candidate::Coursework(i, j) => {
+            let education = &mut resume.get_education_mut()[i].sortable;
+            DroppedItem::Coursework {
+                school: education.get_school_name().clone(),
+                course: education.remove_coursework(j),
+            }
+        }
+        Candidate::Project(i) => DroppedItem::Project {
+            name: resume
+                .get_projects_mut()
+                .remove(i)
+                .sortable
+                .get_project_name()
+                .clone(),
+        },
+    }
+}
+
+// Drops the lowest scoring bullets, coursework and projects one at a time
+// until the resume fits on one pdf page, scoring them the same way the resume was ranked
+pub fn fit_to_one_page(
+    resume: &mut Resume,
+    word_cloud: &WordCloud,
+    scorer: &impl Scorer,
+) -> Result<FitReport> {
+    let fonts = PdfFonts::load()?;
+    let mut dropped = Vec::new();
+    let mut page_count = count_pdf_pages(resume, &fonts)?;
+
+    while page_count > 1 {
+        let Some(candidate) = least_relevant(resume, word_cloud, scorer) else {
+            break;
+        };
+
+        dropped.push(drop_candidate(resume, candidate));
+        page_count = count_pdf_pages(resume, &fonts)?;
+    }
+
+    Ok(FitReport {
+        dropped,
+        page_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ranking::RankingStrategy;
+
+    fn add_job(cv: &mut CV, title: &str, bullets: &[&str]) {
+        cv.add_work_experience(
+            title.to_string(),
+            "Acme".to_string(),
+            None,
+            bullets.iter().map(|bullet| bullet.to_string()).collect(),
+            Date::new(2020, Some(1), None),
+            None,
+        );
+    }
+
+    fn rust_word_cloud() -> WordCloud {
+        let mut word_cloud = WordCloud::new();
+        word_cloud.add_text("Rust services and Rust tooling");
+        word_cloud
+    }
+
+    #[test]
+    fn drops_the_least_relevant_bullet_then_single_bullet_projects() {
+        let mut cv = CV::new();
+        add_job(
+            &mut cv,
+            "Engineer",
+            &["Built Rust services", "Organized the office party"],
+        );
+        add_job(&mut cv, "Intern", &["Planned the team offsite"]);
+        cv.add_project(
+            "Parser".to_string(),
+            vec!["Rust tooling".to_string()],
+            Date::new(2021, None, None),
+            None,
+        );
+
+        let word_cloud = rust_word_cloud();
+        let scorer = RankingStrategy::RawSum;
+        let mut resume = cv.generate_resume(&word_cloud);
+
+        // The intern job is not a candidate even though its only bullet scores 0
+        let candidate = least_relevant(&resume, &word_cloud, &scorer).unwrap();
+        match drop_candidate(&mut resume, candidate) {
+            DroppedItem::Bullet { item, text } => {
+                assert_eq!(item, "Engineer");
+                assert_eq!(text, "Organized the office party");
+            }
+            _ => panic!("expected a bullet to be dropped"),
+        }
+
+        let candidate = least_relevant(&resume, &word_cloud, &scorer).unwrap();
+        match drop_candidate(&mut resume, candidate) {
+            DroppedItem::Project { name } => assert_eq!(name, "Parser"),
+            _ => panic!("expected the project to be dropped"),
+        }
+
+        assert!(least_relevant(&resume, &word_cloud, &scorer).is_none());
+    }
+
+    #[test]
+    fn fits_a_long_resume_on_one_page() {
+        let bullet = "Designed, built and operated Rust services that handled millions \
+                      of requests a day across several regions with careful monitoring";
+        let mut cv = CV::new();
+        for title in ["Engineer", "Developer", "Consultant", "Analyst", "Intern"] {
+            add_job(&mut cv, title, &[bullet; 8]);
+        }
+
+        let word_cloud = rust_word_cloud();
+        let mut resume = cv.generate_resume(&word_cloud);
+        let fonts = PdfFonts::load().unwrap();
+        assert!(count_pdf_pages(&resume, &fonts).unwrap() > 1);
+
+        let report = fit_to_one_page(&mut resume, &word_cloud, &RankingStrategy::RawSum).unwrap();
+
+        assert!(report.fits());
+        assert!(report.ensure_fits().is_ok());
+        assert!(!report.get_dropped().is_empty());
+        assert_eq!(count_pdf_pages(&resume, &fonts).unwrap(), 1);
+        assert!(resume
+            .get_work_experience()
+            .iter()
+            .all(|experience| !experience.sortable.get_job_description().is_empty()));
+    }
+
+    #[test]
+    fn leaves_a_short_resume_alone() {
+        let mut cv = CV::new();
+        add_job(&mut cv, "Engineer", &["Built Rust services"]);
+
+        let word_cloud = rust_word_cloud();
+        let mut resume = cv.generate_resume(&word_cloud);
+        let report = fit_to_one_page(&mut resume, &word_cloud, &RankingStrategy::RawSum).unwrap();
+
+        assert!(report.fits());
+        assert!(report.get_dropped().is_empty());
+        assert_eq!(resume.get_work_experience().len(), 1);
+    }
+}
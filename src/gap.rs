@@ -0,0 +1,264 @@
+use crate::resume::CV;
+use crate::scorer::sum_or_zero;
+use crate::word_cloud::WordCloud;
+use std::fmt::Display;
+
+// Where in the CV a keyword was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeywordLocation {
+    Skill { category: String },
+    WorkExperience { job_title: String },
+    Education { school: String },
+    Project { name: String },
+}
+
+impl Display for KeywordLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeywordLocation::Skill { category } => write!(f, "skills ({})", category),
+            KeywordLocation::WorkExperience { job_title } => {
+                write!(f, "work experience ({})", job_title)
+            }
+            KeywordLocation::Education { school } => write!(f, "education ({})", school),
+            KeywordLocation::Project { name } => write!(f, "project ({})", name),
+        }
+    }
+}
+
+// A keyword from the job description found somewhere in the CV
+pub struct CoveredKeyword {
+    keyword: String,
+    weight: f32,
+    locations: Vec<KeywordLocation>,
+}
+
+impl CoveredKeyword {
+    pub fn get_keyword(&self) -> &String {
+        &self.keyword
+    }
+
+    pub fn get_weight(&self) -> f32 {
+        self.weight
+    }
+
+    pub fn get_locations(&self) -> &Vec<KeywordLocation> {
+        &self.locations
+    }
+}
+
+// Which keywords of a job description the CV covers and which it is missing,
+// every list is sorted with the heaviest keyword first. Keyword phrases are kept
+// apart from single words because their words are keywords too
+pub struct GapReport {
+    covered: Vec<CoveredKeyword>,
+    missing: Vec<(String, f32)>,
+    covered_phrases: Vec<CoveredKeyword>,
+    missing_phrases: Vec<(String, f32)>,
+}
+
+impl GapReport {
+    // Compares every keyword in the word cloud against the skills, work experience,
+    // education and projects of the CV
+    pub fn new(cv: &CV, word_cloud: &WordCloud) -> Self {
+        let mut sections: Vec<(KeywordLocation, Vec<&String>)> = Vec::new();
+
+        let mut categories: Vec<_> = cv.get_skills().get_skill_tree().iter().collect();
+        categories.sort_by(|a, b| a.0.cmp(b.0));
+        for (category, skills) in categories {
+            let location = KeywordLocation::Skill {
+                category: category.clone(),
+            };
+            sections.push((location, skills.iter().collect()));
+        }
+
+        for experience in cv.get_work_experience() {
+            let location = KeywordLocation::WorkExperience {
+                job_title: experience.get_job_title().clone(),
+            };
+            let texts =
+                std::iter::once(experience.get_job_title()).chain(experience.get_job_description());
+            sections.push((location, texts.collect()));
+        }
+
+        for education in cv.get_education() {
+            let location = KeywordLocation::Education {
+                school: education.get_school_name().clone(),
+            };
+            let texts = education
+                .get_coursework()
+                .iter()
+                .chain(education.get_major())
+                .chain(education.get_minor());
+            sections.push((location, texts.collect()));
+        }
+
+        for project in cv.get_projects() {
+            let location = KeywordLocation::Project {
+                name: project.get_project_name().clone(),
+            };
+            let texts = std::iter::once(project.get_project_name())
+                .chain(project.get_project_description());
+            sections.push((location, texts.collect()));
+        }
+
+        // Terms are looked up one text at a time so phrases never span two bullets
+        let section_terms: Vec<_> = sections
+            .into_iter()
+            .map(|(location, texts)| {
+                let terms = texts
+                    .iter()
+                    .flat_map(|text| word_cloud.get_terms_in(text))
+                    .collect::<Vec<_>>();
+                (location, terms)
+            })
+            .collect();
+
+        let mut report = Self {
+            covered: Vec::new(),
+            missing: Vec::new(),
+            covered_phrases: Vec::new(),
+            missing_phrases: Vec::new(),
+        };
+
+        for (keyword, weight) in word_cloud.get_keywords() {
+            let locations: Vec<KeywordLocation> = section_terms
+                .iter()
+                .filter(|(_, terms)| terms.contains(&keyword))
+                .map(|(location, _)| location.clone())
+                .collect();
+
+            // Tokens never contain a space, so a keyword with one is a phrase
+            let (covered, missing) = if keyword.contains(' ') {
+                (&mut report.covered_phrases, &mut report.missing_phrases)
+            } else {
+                (&mut report.covered, &mut report.missing)
+            };
+
+            let keyword = word_cloud.get_display_form(&keyword);
+            if locations.is_empty() {
+                missing.push((keyword, weight));
+            } else {
+                covered.push(CoveredKeyword {
+                    keyword,
+                    weight,
+                    locations,
+                });
+            }
+        }
+
+        report
+    }
+
+    pub fn get_covered(&self) -> &Vec<CoveredKeyword> {
+        &self.covered
+    }
+
+    pub fn get_missing(&self) -> &Vec<(String, f32)> {
+        &self.missing
+    }
+
+    pub fn get_covered_phrases(&self) -> &Vec<CoveredKeyword> {
+        &self.covered_phrases
+    }
+
+    pub fn get_missing_phrases(&self) -> &Vec<(String, f32)> {
+        &self.missing_phrases
+    }
+
+    // Percentage of the total keyword weight that the CV covers. Only single words
+    // count, a phrase weighs as much as its words so counting it too would count
+    // those words twice
+    pub fn get_coverage(&self) -> f32 {
+        let covered = sum_or_zero(self.covered.iter().map(|keyword| keyword.weight));
+        let missing = sum_or_zero(self.missing.iter().map(|(_, weight)| *weight));
+
+        if covered + missing == 0.0 {
+            0.0
+        } else {
+            covered / (covered + missing) * 100.0
+        }
+    }
+}
+
+impl Display for GapReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Coverage: {:.1}%", self.get_coverage())?;
+
+        write_missing(f, "Missing keywords:", &self.missing)?;
+        write_missing(f, "Missing phrases:", &self.missing_phrases)?;
+        write_covered(f, "Covered keywords:", &self.covered)?;
+        write_covered(f, "Covered phrases:", &self.covered_phrases)?;
+
+        Ok(())
+    }
+}
+
+fn write_missing(
+    f: &mut std::fmt::Formatter<'_>,
+    title: &str,
+    missing: &[(String, f32)],
+) -> std::fmt::Result {
+    writeln!(f, "{}", title)?;
+    for (keyword, weight) in missing.iter() {
+        writeln!(f, "    {:>8.3}  {}", weight, keyword)?;
+    }
+
+    Ok(())
+}
+
+fn write_covered(
+    f: &mut std::fmt::Formatter<'_>,
+    title: &str,
+    covered: &[CoveredKeyword],
+) -> std::fmt::Result {
+    writeln!(f, "{}", title)?;
+    for keyword in covered.iter() {
+        let locations: Vec<String> = keyword
+            .locations
+            .iter()
+            .map(|location| location.to_string())
+            .collect();
+        writeln!(
+            f,
+            "    {:>8.3}  {} in {}",
+            keyword.weight,
+            keyword.keyword,
+            locations.join(", ")
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phrases_are_reported_apart_from_their_words() {
+        let word_cloud = WordCloud::create_from_text(
+            "Machine learning is key. We value machine learning and Rust.".to_string(),
+        );
+        let mut cv = CV::new();
+        cv.add_skill("Machine learning".to_string(), None);
+
+        let report = GapReport::new(&cv, &word_cloud);
+
+        let covered: Vec<&String> = report
+            .get_covered()
+            .iter()
+            .map(|keyword| keyword.get_keyword())
+            .collect();
+        let covered_phrases: Vec<&String> = report
+            .get_covered_phrases()
+            .iter()
+            .map(|keyword| keyword.get_keyword())
+            .collect();
+        assert_eq!(covered, vec!["learning", "machine"]);
+        assert_eq!(covered_phrases, vec!["machine learning"]);
+        assert!(report.get_missing_phrases().is_empty());
+
+        // Machine and learning appear twice and Rust, key and value once each
+        assert_eq!(report.get_coverage(), 4.0 / 7.0 * 100.0);
+    }
+}
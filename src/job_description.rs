@@ -0,0 +1,672 @@
+use crate::error::{Result, ResumeError};
+use crate::stopwords::Stopwords;
+use crate::tokenizer::split_words;
+use scraper::{ElementRef, Html, Node, Selector};
+use std::{
+    collections::{HashMap, HashSet},
+    fs, panic,
+    path::Path,
+    str::FromStr,
+};
+
+// Elements that never hold the posting itself
+const SKIPPED_ELEMENTS: [&str; 14] = [
+    "head", "script", "style", "noscript", "template", "nav", "aside", "form", "button", "select",
+    "svg", "iframe", "canvas", "dialog",
+];
+// Elements that are site boilerplate outside the main content, but inside it are
+// part of the posting like the header of an article
+const PAGE_ELEMENTS: [&str; 2] = ["header", "footer"];
+// Roles of elements that are site boilerplate
+const SKIPPED_ROLES: [&str; 6] = [
+    "navigation",
+    "banner",
+    "contentinfo",
+    "search",
+    "menu",
+    "dialog",
+];
+// Elements that start a new line, so text from different blocks is not joined together
+const BLOCK_ELEMENTS: [&str; 24] = [
+    "p",
+    "div",
+    "section",
+    "article",
+    "main",
+    "br",
+    "li",
+    "ul",
+    "ol",
+    "dl",
+    "dt",
+    "dd",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "tr",
+    "td",
+    "th",
+    "table",
+    "blockquote",
+    "pre",
+];
+// Where the posting is when the page marks its main content
+const MAIN_CONTENT: &str = "main, [role=main], article";
+
+// Loads the text of a job description, picking how to read it from the file extension.
+// Html pages have their markup and boilerplate stripped and pdfs have their text
+// extracted, anything else is read as plain text
+pub fn load_job_description(filename: String) -> Result<String> {
+    let extension = Path::new(&filename)
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_ascii_lowercase());
+
+    match extension.as_deref() {
+        Some("html") | Some("htm") => {
+            let html =
+                fs::read_to_string(&filename).map_err(|error| ResumeError::io(filename, error))?;
+            Ok(text_from_html(&html))
+        }
+        Some("pdf") => {
+            let bytes =
+                fs::read(&filename).map_err(|error| ResumeError::io(filename.clone(), error))?;
+            text_from_pdf(&bytes).map_err(|error| ResumeError::extract(filename, error))
+        }
+        _ => fs::read_to_string(&filename).map_err(|error| ResumeError::io(filename, error)),
+    }
+}
+
+// The readable text of an html page, one paragraph per block. Scripts, styles, navigation
+// and forms are left out, and when the page marks its main content only that is used.
+// Headers and footers are left out unless they are inside the main content
+pub fn text_from_html(html: &str) -> String {
+    let document = Html::parse_document(html);
+    let main_content = Selector::parse(MAIN_CONTENT).expect("main content selector is valid");
+
+    let mut text = String::new();
+    match document.select(&main_content).next() {
+        Some(main) => append_text(main, true, &mut text),
+        None => append_text(document.root_element(), false, &mut text),
+    }
+
+    // Collapse the whitespace html ignores and drop empty lines, every block
+    // becomes its own paragraph
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<&str>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+fn is_boilerplate(element: &ElementRef, in_main: bool) -> bool {
+    let element = element.value();
+
+    SKIPPED_ELEMENTS.contains(&element.name())
+        || (!in_main && PAGE_ELEMENTS.contains(&element.name()))
+        || element.attr("hidden").is_some()
+        || element.attr("aria-hidden") == Some("true")
+        || element
+            .attr("role")
+            .is_some_and(|role| SKIPPED_ROLES.contains(&role))
+}
+
+fn append_text(element: ElementRef, in_main: bool, text: &mut String) {
+    let block = BLOCK_ELEMENTS.contains(&element.value().name());
+    if block {
+        text.push('\n');
+    }
+
+    // Mark list items so they are not mistaken for headings
+    if element.value().name() == "li" {
+        text.push_str("• ");
+    }
+
+    for child in element.children() {
+        match child.value() {
+            Node::Text(child_text) => text.push_str(child_text),
+            Node::Element(_) => {
+                if let Some(child) = ElementRef::wrap(child) {
+                    if !is_boilerplate(&child, in_main) {
+                        append_text(child, in_main, text);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if block {
+        text.push('\n');
+    }
+}
+
+// The text of every page of a pdf in order
+pub fn text_from_pdf(bytes: &[u8]) -> std::result::Result<String, String> {
+    // pdf-extract panics on some malformed pdfs instead of returning an error
+    match panic::catch_unwind(|| pdf_extract::extract_text_from_mem(bytes)) {
+        Ok(Ok(text)) => Ok(text),
+        Ok(Err(error)) => Err(error.to_string()),
+        Err(_) => Err(String::from("the pdf could not be read")),
+    }
+}
+
+// What a section of a job posting is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SectionKind {
+    // Qualifications the candidate must have
+    Required,
+    // Qualifications that are nice to have
+    Preferred,
+    // What the job involves day to day
+    Responsibilities,
+    // Introduction to the role
+    Overview,
+    // About the company, usually the same for every posting
+    Company,
+    // Pay, perks and legal notices
+    Benefits,
+    // Text before the first heading or under a heading that is not recognized
+    Other,
+}
+
+impl SectionKind {
+    // Guesses the kind of section from its heading, more specific phrases are checked first
+    // so "Preferred Qualifications" is preferred and not required
+    pub fn from_heading(heading: &str) -> Self {
+        let heading = heading.to_lowercase().replace('’', "'");
+
+        HEADING_PHRASES
+            .iter()
+            .find(|(_, phrases)| phrases.iter().any(|phrase| heading.contains(phrase)))
+            .map_or(SectionKind::Other, |(kind, _)| *kind)
+    }
+}
+
+// Phrases that give away the kind of section in a heading, in the order they are checked
+const HEADING_PHRASES: [(SectionKind, &[&str]); 6] = [
+    (
+        SectionKind::Preferred,
+        &[
+            "nice to have",
+            "preferred",
+            "bonus",
+            "plus",
+            "desired",
+            "ideally",
+        ],
+    ),
+    (
+        SectionKind::Company,
+        &[
+            "our company",
+            "about us",
+            "who we are",
+            "about the company",
+            "our mission",
+            "our team",
+            "life at",
+        ],
+    ),
+    (SectionKind::Benefits, &BENEFITS_PHRASES),
+    (
+        SectionKind::Required,
+        &[
+            "need",
+            "require",
+            "qualification",
+            "must have",
+            "you bring",
+            "you have",
+            "skills",
+            "looking for",
+            "experience",
+        ],
+    ),
+    (
+        SectionKind::Responsibilities,
+        &[
+            "you'll do",
+            "you will do",
+            "responsibilit",
+            "day to day",
+            "what you do",
+            "duties",
+            "you will",
+        ],
+    ),
+    (
+        SectionKind::Overview,
+        &[
+            "opportunity",
+            "overview",
+            "the role",
+            "about the job",
+            "about the position",
+            "summary",
+        ],
+    ),
+];
+
+// Words that headings use around the phrases, like "What You Will Need to Succeed"
+const HEADING_WORDS: [&str; 14] = [
+    "succeed",
+    "role",
+    "job",
+    "position",
+    "key",
+    "minimum",
+    "basic",
+    "additional",
+    "technical",
+    "points",
+    "ideal",
+    "candidate",
+    "qualified",
+    "details",
+];
+
+// Phrases about pay and benefits, in headings and in text that has no heading
+const BENEFITS_PHRASES: [&str; 7] = [
+    "benefit",
+    "compensation",
+    "perks",
+    "we offer",
+    "salary",
+    "pay range",
+    "equal opportunity",
+];
+
+impl FromStr for SectionKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "required" => Ok(SectionKind::Required),
+            "preferred" => Ok(SectionKind::Preferred),
+            "responsibilities" => Ok(SectionKind::Responsibilities),
+            "overview" => Ok(SectionKind::Overview),
+            "company" => Ok(SectionKind::Company),
+            "benefits" => Ok(SectionKind::Benefits),
+            "other" => Ok(SectionKind::Other),
+            other => Err(format!("unknown section: {}", other)),
+        }
+    }
+}
+
+// How much the words in each kind of section count towards the word cloud
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionWeights {
+    multipliers: HashMap<SectionKind, f32>,
+}
+
+impl Default for SectionWeights {
+    // Requirements count three times, nice to haves one and a half times and the
+    // company blurb and benefits not at all, everything else counts once
+    fn default() -> Self {
+        let mut weights = Self {
+            multipliers: HashMap::new(),
+        };
+        weights.set_multiplier(SectionKind::Required, 3.0);
+        weights.set_multiplier(SectionKind::Preferred, 1.5);
+        weights.set_multiplier(SectionKind::Company, 0.0);
+        weights.set_multiplier(SectionKind::Benefits, 0.0);
+
+        weights
+    }
+}
+
+impl SectionWeights {
+    // Every section counts once
+    pub fn new() -> Self {
+        Self {
+            multipliers: HashMap::new(),
+        }
+    }
+
+    pub fn get_multiplier(&self, kind: SectionKind) -> f32 {
+        *self.multipliers.get(&kind).unwrap_or(&1.0)
+    }
+
+    pub fn set_multiplier(&mut self, kind: SectionKind, multiplier: f32) {
+        self.multipliers.insert(kind, multiplier);
+    }
+}
+
+impl FromStr for SectionWeights {
+    type Err = String;
+
+    // Parses overrides of the default weights like "required=3,company=0"
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut weights = Self::default();
+
+        for pair in s.split(',').filter(|pair| !pair.trim().is_empty()) {
+            let (kind, multiplier) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected section=multiplier, got: {}", pair))?;
+            let multiplier = multiplier
+                .trim()
+                .parse::<f32>()
+                .map_err(|_| format!("invalid multiplier for {}: {}", kind, multiplier))?;
+
+            weights.set_multiplier(kind.parse()?, multiplier);
+        }
+
+        Ok(weights)
+    }
+}
+
+// A heading and the paragraphs under it
+pub struct JobSection {
+    heading: String,
+    kind: SectionKind,
+    paragraphs: Vec<String>,
+}
+
+impl JobSection {
+    pub fn get_heading(&self) -> &String {
+        &self.heading
+    }
+
+    pub fn get_kind(&self) -> SectionKind {
+        self.kind
+    }
+
+    pub fn get_paragraphs(&self) -> &Vec<String> {
+        &self.paragraphs
+    }
+}
+
+// Headings are short lines that do not end a sentence
+const MAX_HEADING_WORDS: usize = 8;
+// Lines starting with these are list items, never headings
+const LIST_MARKERS: [char; 4] = ['•', '-', '*', '–'];
+
+// A job posting split into its sections, with repeated paragraphs removed
+pub struct JobDescription {
+    sections: Vec<JobSection>,
+}
+
+impl JobDescription {
+    // Paragraphs are separated by blank lines. A paragraph that is a single short,
+    // unindented line without closing punctuation is a heading. Once the first section
+    // has started only lines made of a heading phrase and words headings use around it
+    // start a new section, others are kept as text. A heading with nothing under it
+    // is kept as text too. A paragraph that already appeared earlier in the posting
+    // is left out, even if its punctuation or spacing changed
+    pub fn parse(text: &str) -> Self {
+        let stopwords = Stopwords::english();
+        let mut sections = vec![JobSection {
+            heading: String::new(),
+            kind: SectionKind::Other,
+            paragraphs: Vec::new(),
+        }];
+        let mut seen = HashSet::new();
+        let mut after_list = false;
+
+        for (paragraph, indented) in split_paragraphs(text) {
+            let kind = SectionKind::from_heading(&paragraph);
+            let in_first_section = sections.len() == 1;
+
+            if !indented
+                && is_heading(&paragraph)
+                && (in_first_section
+                    || (kind != SectionKind::Other && is_heading_phrase(&paragraph, &stopwords)))
+            {
+                sections.push(JobSection {
+                    heading: paragraph,
+                    kind,
+                    paragraphs: Vec::new(),
+                });
+            } else {
+                // Text that follows a list without a heading of its own, like the pay range
+                // at the end of a posting, is not part of the listed section
+                let benefits = !indented && is_benefits_paragraph(&paragraph);
+                let current = sections.last().unwrap().kind;
+                if (benefits && current != SectionKind::Benefits) || (!indented && after_list) {
+                    sections.push(JobSection {
+                        heading: String::new(),
+                        kind: if benefits {
+                            SectionKind::Benefits
+                        } else {
+                            SectionKind::Other
+                        },
+                        paragraphs: Vec::new(),
+                    });
+                }
+
+                // Compared as tokenizer words so "C++" and "C#" stay different
+                let key = split_words(&paragraph).collect::<Vec<String>>().join(" ");
+
+                if seen.insert(key) {
+                    sections.last_mut().unwrap().paragraphs.push(paragraph);
+                }
+            }
+
+            after_list = indented;
+        }
+
+        for section in sections.iter_mut() {
+            if section.paragraphs.is_empty() && !section.heading.is_empty() {
+                section.paragraphs.push(section.heading.clone());
+            }
+        }
+        sections.retain(|section| !section.paragraphs.is_empty());
+
+        Self { sections }
+    }
+
+    pub fn load_from_file(filename: String) -> Result<Self> {
+        Ok(Self::parse(&load_job_description(filename)?))
+    }
+
+    pub fn get_sections(&self) -> &Vec<JobSection> {
+        &self.sections
+    }
+}
+
+// Splits text on blank lines, collapsing the whitespace inside each paragraph
+// but keeping its line breaks. Also returns whether the paragraph was indented,
+// which is how plain text postings usually show list items
+fn split_paragraphs(text: &str) -> Vec<(String, bool)> {
+    let mut paragraphs = Vec::new();
+    let mut lines: Vec<String> = Vec::new();
+    let mut indented = false;
+
+    for line in text.lines().chain(std::iter::once("")) {
+        let collapsed = line.split_whitespace().collect::<Vec<&str>>().join(" ");
+        if collapsed.is_empty() {
+            if !lines.is_empty() {
+                paragraphs.push((lines.join("\n"), indented));
+                lines.clear();
+            }
+        } else {
+            if lines.is_empty() {
+                indented = line.starts_with(char::is_whitespace);
+            }
+            lines.push(collapsed);
+        }
+    }
+
+    paragraphs
+}
+
+fn is_benefits_paragraph(paragraph: &str) -> bool {
+    let paragraph = paragraph.to_lowercase();
+    BENEFITS_PHRASES
+        .iter()
+        .any(|phrase| paragraph.contains(phrase))
+}
+
+// Whether every word of the line is part of a heading phrase in it, a stopword or a
+// word headings use, so a short line like "Kubernetes experience" is not a heading
+fn is_heading_phrase(paragraph: &str, stopwords: &Stopwords) -> bool {
+    let heading = paragraph.to_lowercase().replace('’', "'");
+    let phrases: Vec<&str> = HEADING_PHRASES
+        .iter()
+        .flat_map(|(_, phrases)| phrases.iter().copied())
+        .filter(|phrase| heading.contains(phrase))
+        .collect();
+
+    // One word phrases like "require" also match longer forms like "requirements"
+    let in_phrase = |word: &str| {
+        phrases.iter().any(|phrase| match phrase.contains(' ') {
+            true => phrase.split(' ').any(|phrase_word| phrase_word == word),
+            false => word.starts_with(phrase),
+        })
+    };
+
+    let mut words = split_words(&heading);
+    words.all(|word| {
+        stopwords.contains(&word) || HEADING_WORDS.contains(&word.as_str()) || in_phrase(&word)
+    })
+}
+
+fn is_heading(paragraph: &str) -> bool {
+    !paragraph.contains('\n')
+        && paragraph.split_whitespace().count() <= MAX_HEADING_WORDS
+        && !paragraph.starts_with(LIST_MARKERS)
+        && !paragraph.ends_with(['.', '!', '?', ',', ';'])
+        && paragraph.chars().any(char::is_alphabetic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(job: &'a JobDescription, text: &str) -> &'a JobSection {
+        job.get_sections()
+            .iter()
+            .find(|section| {
+                section
+                    .get_paragraphs()
+                    .iter()
+                    .any(|paragraph| paragraph.contains(text))
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn parses_the_sample_job_description() {
+        let job = JobDescription::parse(include_str!("../res/job_desc.txt"));
+
+        let required = find(&job, "0-1 + years of experience");
+        assert_eq!(required.get_kind(), SectionKind::Required);
+        assert_eq!(required.get_heading(), "What You Will Need to Succeed");
+        assert_eq!(required.get_paragraphs().len(), 8);
+
+        assert_eq!(
+            find(&job, "Our compensation reflects").get_kind(),
+            SectionKind::Benefits
+        );
+        assert_eq!(
+            find(&job, "digital experiences").get_kind(),
+            SectionKind::Company
+        );
+
+        let company_paragraphs = job
+            .get_sections()
+            .iter()
+            .flat_map(|section| section.get_paragraphs())
+            .filter(|paragraph| paragraph.contains("digital experiences"))
+            .count();
+        assert_eq!(company_paragraphs, 1);
+    }
+
+    #[test]
+    fn indented_lines_are_not_headings() {
+        let job =
+            JobDescription::parse("Requirements\n\n    Rust experience\n\n    Go experience\n");
+
+        assert_eq!(job.get_sections().len(), 1);
+        assert_eq!(job.get_sections()[0].get_paragraphs().len(), 2);
+    }
+
+    #[test]
+    fn html_leaves_out_scripts_and_navigation() {
+        let html = "<html><head><title>Jobs</title><style>p { color: red }</style></head>\
+                    <body><header>Acme Careers</header><nav><a>Home</a></nav>\
+                    <h1>Rust Engineer</h1><script>track()</script>\
+                    <ul><li>Rust</li><li>Kafka</li></ul>\
+                    <div role=\"navigation\">Sitemap</div><footer>Contact us</footer></body></html>";
+
+        assert_eq!(text_from_html(html), "Rust Engineer\n\n• Rust\n\n• Kafka");
+    }
+
+    #[test]
+    fn html_only_reads_the_main_content() {
+        let html = "<body><div>Sign in</div><article><header><h1>Rust Engineer</h1></header>\
+                    <p>Build services.</p><footer>Apply by May</footer></article>\
+                    <footer>Acme Inc</footer></body>";
+
+        assert_eq!(
+            text_from_html(html),
+            "Rust Engineer\n\nBuild services.\n\nApply by May"
+        );
+    }
+
+    #[test]
+    fn unreadable_pdfs_are_extract_errors() {
+        assert!(text_from_pdf(b"not a pdf").is_err());
+
+        let filename = std::env::temp_dir().join("resume_builder_broken.pdf");
+        fs::write(&filename, "%PDF-1.4 not really").unwrap();
+        let result = load_job_description(filename.to_string_lossy().to_string());
+        fs::remove_file(&filename).unwrap();
+
+        assert!(matches!(result, Err(ResumeError::Extract { .. })));
+    }
+
+    #[test]
+    fn short_lines_of_text_are_not_headings() {
+        let job = JobDescription::parse(
+            "Requirements\n\nPython\n\nDocker\n\nKubernetes experience\n\nSQL skills\n",
+        );
+
+        assert_eq!(job.get_sections().len(), 1);
+        assert_eq!(job.get_sections()[0].get_kind(), SectionKind::Required);
+        assert_eq!(
+            job.get_sections()[0].get_paragraphs(),
+            &vec!["Python", "Docker", "Kubernetes experience", "SQL skills"]
+        );
+
+        let job = JobDescription::parse(
+            "Requirements\n\nExperience with Kubernetes\n\nStrong Rust skills\n\nKafka a plus\n",
+        );
+        assert_eq!(job.get_sections().len(), 1);
+        assert_eq!(job.get_sections()[0].get_paragraphs().len(), 3);
+    }
+
+    #[test]
+    fn headings_with_nothing_under_them_are_kept_as_text() {
+        let job = JobDescription::parse("Kubernetes and Rust\n\nRequirements\n\nPython\n");
+
+        let paragraphs: Vec<&String> = job
+            .get_sections()
+            .iter()
+            .flat_map(|section| section.get_paragraphs())
+            .collect();
+        assert_eq!(paragraphs, vec!["Kubernetes and Rust", "Python"]);
+        assert_eq!(job.get_sections()[1].get_kind(), SectionKind::Required);
+    }
+
+    #[test]
+    fn repeated_paragraphs_are_compared_by_words() {
+        let job = JobDescription::parse(
+            "Requirements\n\nExperience with C++\n\nExperience with C#\n\n\
+             Experience with .NET\n\nExperience with NET\n\nexperience  with C++!\n",
+        );
+
+        assert_eq!(
+            job.get_sections()[0].get_paragraphs(),
+            &vec![
+                "Experience with C++",
+                "Experience with C#",
+                "Experience with .NET",
+                "Experience with NET"
+            ]
+        );
+    }
+}
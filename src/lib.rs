@@ -1,15 +1,112 @@
+pub mod batch;
+pub mod breakdown;
+pub mod debug;
+pub mod docx;
+pub mod error;
+pub mod experience;
+pub mod fit;
+pub mod gap;
+pub mod job_description;
+pub mod markdown;
+pub mod pdf;
+pub mod ranking;
+pub mod renderer;
+pub mod resume;
+pub mod scorer;
+pub mod stemming;
+pub mod stopwords;
+pub mod svg;
+pub mod synonyms;
+pub mod taxonomy;
+pub mod tokenizer;
+pub mod word_cloud;
 
+pub use batch::{tailor_batch, BatchEntry, BatchReport};
+pub use breakdown::ScoreBreakdown;
+pub use debug::render_debug_from_resume;
+pub use docx::{generate_docx_from_resume, render_docx_from_resume, write_docx_from_resume};
+pub use error::{Result, ResumeError};
+pub use experience::{ExperienceReport, ExperienceRequirement, RequirementCheck};
+pub use fit::{fit_to_one_page, FitReport};
+pub use gap::GapReport;
+pub use job_description::{load_job_description, JobDescription, SectionKind, SectionWeights};
+pub use markdown::{
+    generate_markdown_from_resume, render_markdown_from_resume, write_markdown_from_resume,
+};
+pub use pdf::{create_pdf_from_resume, render_pdf_from_resume, write_pdf_from_resume};
+pub use ranking::RankingStrategy;
+pub use renderer::ResumeRenderer;
+pub use resume::{Resume, CV};
+pub use scorer::{Bm25, Scorer};
+pub use stemming::StemmingLanguage;
+pub use stopwords::Stopwords;
+pub use svg::{render_word_cloud_svg, write_word_cloud_svg};
+pub use synonyms::Synonyms;
+pub use taxonomy::{SkillSuggestion, SkillSuggestions, Taxonomy, TaxonomySkill};
+pub use tokenizer::Tokenizer;
+pub use word_cloud::WordCloud;
 
-// pub fn create_CV() {
+use std::str::FromStr;
 
-// }
+// Output formats that a tailored resume can be rendered to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Markdown,
+    Docx,
+    Pdf,
+}
 
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
+impl OutputFormat {
+    // File extension used when writing this format to disk
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Markdown => "md",
+            OutputFormat::Docx => "docx",
+            OutputFormat::Pdf => "pdf",
+        }
+    }
+}
 
-//     #[test]
-//     fn it_works() {
-        
-//     }
-// }
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "md" | "markdown" => Ok(OutputFormat::Markdown),
+            "docx" => Ok(OutputFormat::Docx),
+            "pdf" => Ok(OutputFormat::Pdf),
+            other => Err(format!("unknown output format: {}", other)),
+        }
+    }
+}
+
+// Renders the resume in memory in the given format
+pub fn render(resume: &Resume, format: OutputFormat) -> Result<Vec<u8>> {
+    match format {
+        OutputFormat::Markdown => render_markdown_from_resume(resume),
+        OutputFormat::Docx => render_docx_from_resume(resume),
+        OutputFormat::Pdf => render_pdf_from_resume(resume),
+    }
+}
+
+// Renders the resume in the given format and writes it to a file
+pub fn write_resume(resume: &Resume, format: OutputFormat, output_file: String) -> Result<()> {
+    match format {
+        OutputFormat::Markdown => generate_markdown_from_resume(resume, output_file),
+        OutputFormat::Docx => generate_docx_from_resume(resume, output_file),
+        OutputFormat::Pdf => create_pdf_from_resume(resume, output_file),
+    }
+}
+
+// Tailors the CV to the job description and renders the resume in the given format.
+// Sections of the job description are weighted by default, the same as on the command line
+pub fn tailor_and_render(
+    cv: &CV,
+    job_description: String,
+    format: OutputFormat,
+) -> Result<Vec<u8>> {
+    let word_cloud = WordCloud::create_from_text(job_description);
+    let resume = cv.generate_resume(&word_cloud);
+
+    render(&resume, format)
+}
@@ -0,0 +1,552 @@
+use clap::{Args, Parser, Subcommand};
+use resume_builder::{
+    fit_to_one_page, render_debug_from_resume, tailor_batch, write_resume, write_word_cloud_svg,
+    ExperienceReport, FitReport, GapReport, OutputFormat, RankingStrategy, Resume, ResumeError,
+    Scorer, SectionWeights, SkillSuggestions, StemmingLanguage, Stopwords, Synonyms, Taxonomy,
+    Tokenizer, WordCloud, CV,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+#[derive(Parser)]
+#[command(
+    name = "resume-builder",
+    about = "Tailor a CV to a job description and render it"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Tailor the CV to a job description and render the resume
+    Build {
+        /// CV json file
+        #[arg(long)]
+        cv: PathBuf,
+        /// Job description text, html or pdf file
+        #[arg(long)]
+        job: PathBuf,
+        /// Comma separated list of output formats (pdf, docx, md)
+        #[arg(long, value_delimiter = ',', default_value = "pdf")]
+        format: Vec<OutputFormat>,
+        /// Directory to write the rendered resumes to
+        #[arg(long, default_value = ".")]
+        out: PathBuf,
+        /// Drop the least relevant content until the resume fits on one page
+        #[arg(long)]
+        one_page: bool,
+        /// How items are ranked (raw-sum, ratio, length-normalized, max-term, bm25)
+        #[arg(long, default_value = "length-normalized")]
+        ranking: RankingStrategy,
+        #[command(flatten)]
+        word_cloud: WordCloudArgs,
+    },
+    /// Tailor the CV to every job description in a directory, one output directory per posting
+    Batch {
+        /// CV json file
+        #[arg(long)]
+        cv: PathBuf,
+        /// Directory of job description text, html or pdf files
+        #[arg(long)]
+        jobs: PathBuf,
+        /// Comma separated list of output formats (pdf, docx, md)
+        #[arg(long, value_delimiter = ',', default_value = "pdf")]
+        format: Vec<OutputFormat>,
+        /// Directory to create the posting directories in
+        #[arg(long, default_value = ".")]
+        out: PathBuf,
+        /// Drop the least relevant content until each resume fits on one page
+        #[arg(long)]
+        one_page: bool,
+        /// How items are ranked (raw-sum, ratio, length-normalized, max-term, bm25)
+        #[arg(long, default_value = "length-normalized")]
+        ranking: RankingStrategy,
+        #[command(flatten)]
+        word_cloud: WordCloudArgs,
+    },
+    /// Print how well every item in the CV scores against a job description
+    Score {
+        #[arg(long)]
+        cv: PathBuf,
+        #[arg(long)]
+        job: PathBuf,
+        /// How items are ranked (raw-sum, ratio, length-normalized, max-term, bm25)
+        #[arg(long, default_value = "length-normalized")]
+        ranking: RankingStrategy,
+        /// Print the ranked resume with the keywords every item and bullet matched
+        #[arg(long)]
+        explain: bool,
+        #[command(flatten)]
+        word_cloud: WordCloudArgs,
+    },
+    /// List the job description keywords the CV covers and the ones it is missing
+    Gap {
+        #[arg(long)]
+        cv: PathBuf,
+        #[arg(long)]
+        job: PathBuf,
+        #[command(flatten)]
+        word_cloud: WordCloudArgs,
+    },
+    /// Check the CV against the years of experience a job description asks for
+    Experience {
+        #[arg(long)]
+        cv: PathBuf,
+        #[arg(long)]
+        job: PathBuf,
+        #[command(flatten)]
+        word_cloud: WordCloudArgs,
+    },
+    /// Suggest taxonomy skills that the CV bullets mention but the skills section does not list
+    SuggestSkills {
+        #[arg(long)]
+        cv: PathBuf,
+        /// Csv file with skill, category and aliases columns
+        #[arg(long)]
+        taxonomy: PathBuf,
+        /// Write a copy of the CV with the suggested skills added to this file
+        #[arg(long)]
+        save: Option<PathBuf>,
+        #[command(flatten)]
+        word_cloud: WordCloudArgs,
+    },
+    /// Draw the job description keywords as an svg word cloud
+    Cloud {
+        /// Job description text, html or pdf file
+        #[arg(long)]
+        job: PathBuf,
+        /// CV json file, colors keywords by whether the CV covers them
+        #[arg(long)]
+        cv: Option<PathBuf>,
+        /// Svg file to write
+        #[arg(long, default_value = "word_cloud.svg")]
+        out: PathBuf,
+        #[command(flatten)]
+        word_cloud: WordCloudArgs,
+    },
+    /// Check that a CV file can be loaded
+    Validate {
+        #[arg(long)]
+        cv: PathBuf,
+    },
+}
+
+// Options for how job descriptions are turned into word clouds
+#[derive(Args)]
+struct WordCloudArgs {
+    /// File of extra stopwords to ignore on top of the built in English list
+    #[arg(long)]
+    stopwords: Option<PathBuf>,
+    /// Stem words in this language so "developed" matches "develop" (english, german, ...)
+    #[arg(long)]
+    stem: Option<StemmingLanguage>,
+    /// File of extra aliases on top of the built in ones, one "Canonical: alias, alias" per line
+    #[arg(long)]
+    synonyms: Option<PathBuf>,
+    /// File of phrases to treat as keywords, one per line
+    #[arg(long)]
+    phrases: Option<PathBuf>,
+    /// Directory of other job descriptions, words common to all of them weigh less
+    #[arg(long)]
+    corpus: Option<PathBuf>,
+    /// Section multipliers to change, like required=3,preferred=1.5,company=0
+    #[arg(long)]
+    section_weights: Option<SectionWeights>,
+    /// Count every word once instead of weighting job description sections
+    #[arg(long, conflicts_with = "section_weights")]
+    no_sections: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Build {
+            cv,
+            job,
+            format,
+            out,
+            one_page,
+            ranking,
+            word_cloud,
+        } => word_cloud
+            .load_with_cv(&cv, &job)
+            .and_then(|(cv, word_cloud)| {
+                build(&cv, &job, &word_cloud, &format, &out, one_page, ranking)
+            }),
+        Command::Batch {
+            cv,
+            jobs,
+            format,
+            out,
+            one_page,
+            ranking,
+            word_cloud,
+        } => word_cloud
+            .template_with_cv(&cv)
+            .and_then(|(cv, word_cloud)| {
+                batch(&cv, &word_cloud, &jobs, &format, &out, one_page, ranking)
+            }),
+        Command::Score {
+            cv,
+            job,
+            ranking,
+            explain,
+            word_cloud,
+        } => word_cloud
+            .load_with_cv(&cv, &job)
+            .and_then(|(cv, word_cloud)| score(&cv, &word_cloud, ranking, explain)),
+        Command::Gap {
+            cv,
+            job,
+            word_cloud,
+        } => word_cloud
+            .load_with_cv(&cv, &job)
+            .and_then(|(cv, word_cloud)| gap(&cv, &word_cloud)),
+        Command::Experience {
+            cv,
+            job,
+            word_cloud,
+        } => word_cloud
+            .template_with_cv(&cv)
+            .and_then(|(cv, word_cloud)| experience(&cv, &job, &word_cloud)),
+        Command::SuggestSkills {
+            cv,
+            taxonomy,
+            save,
+            word_cloud,
+        } => word_cloud
+            .template_with_cv(&cv)
+            .and_then(|(cv, word_cloud)| {
+                suggest_skills(cv, &taxonomy, save.as_deref(), &word_cloud)
+            }),
+        Command::Cloud {
+            job,
+            cv,
+            out,
+            word_cloud,
+        } => match cv {
+            Some(cv) => word_cloud
+                .load_with_cv(&cv, &job)
+                .and_then(|(cv, word_cloud)| cloud(&word_cloud, Some(&cv), &out)),
+            None => word_cloud
+                .load(&job)
+                .and_then(|word_cloud| cloud(&word_cloud, None, &out)),
+        },
+        Command::Validate { cv } => validate(&cv),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+
+            // Exit codes follow sysexits.h so scripts can tell bad input from a failed write
+            match error {
+                ResumeError::Parse { .. }
+                | ResumeError::Csv { .. }
+                | ResumeError::Extract { .. } => ExitCode::from(65),
+                ResumeError::Io { .. } => ExitCode::from(74),
+                _ => ExitCode::FAILURE,
+            }
+        }
+    }
+}
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+// Loads a CV, renaming its skills with the synonyms the word cloud uses
+fn load_cv(path: &Path, synonyms: &Synonyms) -> Result<CV, ResumeError> {
+    let mut cv = CV::new();
+    cv.load_from_file(path_string(path))?;
+    cv.set_synonyms(synonyms.clone());
+    Ok(cv)
+}
+
+impl WordCloudArgs {
+    // Builds the word cloud for a job description
+    fn load(&self, job: &Path) -> Result<WordCloud, ResumeError> {
+        let mut word_cloud = self.template(self.tokenizer(self.synonyms()?)?)?;
+        word_cloud.add_text_file(path_string(job))?;
+
+        Ok(word_cloud)
+    }
+
+    // Loads the CV and builds the word cloud for a job description
+    fn load_with_cv(&self, cv: &Path, job: &Path) -> Result<(CV, WordCloud), ResumeError> {
+        let (cv, mut word_cloud) = self.template_with_cv(cv)?;
+        word_cloud.add_text_file(path_string(job))?;
+
+        Ok((cv, word_cloud))
+    }
+
+    // Loads the CV with the synonyms of these options and an empty word cloud
+    // that never stems the skills of the CV
+    fn template_with_cv(&self, cv: &Path) -> Result<(CV, WordCloud), ResumeError> {
+        let synonyms = self.synonyms()?;
+        let cv = load_cv(cv, &synonyms)?;
+
+        let mut tokenizer = self.tokenizer(synonyms)?;
+        tokenizer.keep_names(cv.get_skills().get_skill_tree().values().flatten());
+
+        Ok((cv, self.template(tokenizer)?))
+    }
+
+    // The built in aliases and any from the synonym file
+    fn synonyms(&self) -> Result<Synonyms, ResumeError> {
+        let mut synonyms = Synonyms::builtin();
+        if let Some(file) = &self.synonyms {
+            synonyms.extend(Synonyms::load_from_file(path_string(file))?);
+        }
+
+        Ok(synonyms)
+    }
+
+    // A tokenizer ignoring the built in stopwords and any from the stopword file
+    fn tokenizer(&self, synonyms: Synonyms) -> Result<Tokenizer, ResumeError> {
+        let mut stopwords = Stopwords::english();
+        if let Some(file) = &self.stopwords {
+            stopwords.extend(Stopwords::load_from_file(path_string(file))?.into_words());
+        }
+
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.set_stopwords(stopwords);
+        tokenizer.set_stemming(self.stem);
+        tokenizer.set_synonyms(synonyms);
+
+        Ok(tokenizer)
+    }
+
+    // An empty word cloud set up with these options
+    fn template(&self, tokenizer: Tokenizer) -> Result<WordCloud, ResumeError> {
+        let mut word_cloud = WordCloud::with_tokenizer(tokenizer);
+        if let Some(phrases) = &self.phrases {
+            word_cloud.add_phrases_from_file(path_string(phrases))?;
+        }
+        if let Some(corpus) = &self.corpus {
+            word_cloud.load_corpus(path_string(corpus))?;
+        }
+        if self.no_sections {
+            word_cloud.set_section_weights(None);
+        } else if let Some(section_weights) = &self.section_weights {
+            word_cloud.set_section_weights(Some(section_weights.clone()));
+        }
+
+        Ok(word_cloud)
+    }
+}
+
+// Ranks the CV with the scorer and fits it to one page if asked to
+fn tailor(
+    cv: &CV,
+    word_cloud: &WordCloud,
+    scorer: &impl Scorer,
+    one_page: bool,
+) -> Result<(Resume, Option<FitReport>), ResumeError> {
+    let mut resume = cv.generate_resume_with_scorer(word_cloud, scorer);
+
+    if !one_page {
+        return Ok((resume, None));
+    }
+
+    let fit = fit_to_one_page(&mut resume, word_cloud, scorer)?;
+    print!("{}", fit);
+
+    Ok((resume, Some(fit)))
+}
+
+fn build(
+    cv: &CV,
+    job: &Path,
+    word_cloud: &WordCloud,
+    formats: &[OutputFormat],
+    out: &Path,
+    one_page: bool,
+    ranking: RankingStrategy,
+) -> Result<(), ResumeError> {
+    let (resume, fit) = match ranking {
+        RankingStrategy::Bm25 => tailor(cv, word_cloud, &cv.create_bm25(word_cloud), one_page)?,
+        ranking => tailor(cv, word_cloud, &ranking, one_page)?,
+    };
+
+    fs::create_dir_all(out).map_err(|error| ResumeError::io(path_string(out), error))?;
+
+    for format in formats {
+        let output_file = path_string(&out.join(format!("resume.{}", format.extension())));
+        write_resume(&resume, *format, output_file.clone())?;
+
+        println!("Wrote {}", output_file);
+    }
+
+    // The experience requirements go next to the resume when the posting has any
+    let report = ExperienceReport::from_job_file(cv, path_string(job), word_cloud.get_tokenizer())?;
+    if !report.is_empty() {
+        let output_file = path_string(&out.join("requirements.txt"));
+        fs::write(&output_file, report.to_string())
+            .map_err(|error| ResumeError::io(output_file.clone(), error))?;
+
+        println!("Wrote {}", output_file);
+    }
+
+    // The resume is written even if it does not fit, but the command still fails
+    match fit {
+        Some(fit) => fit.ensure_fits(),
+        None => Ok(()),
+    }
+}
+
+fn batch(
+    cv: &CV,
+    word_cloud: &WordCloud,
+    jobs: &Path,
+    formats: &[OutputFormat],
+    out: &Path,
+    one_page: bool,
+    ranking: RankingStrategy,
+) -> Result<(), ResumeError> {
+    let (jobs, out) = (path_string(jobs), path_string(out));
+    let report = match ranking {
+        RankingStrategy::Bm25 => tailor_batch(
+            cv,
+            word_cloud,
+            jobs,
+            out,
+            formats,
+            one_page,
+            &cv.create_bm25(word_cloud),
+        )?,
+        ranking => tailor_batch(cv, word_cloud, jobs, out, formats, one_page, &ranking)?,
+    };
+    print!("{}", report);
+
+    match report.failed() {
+        0 => Ok(()),
+        failed => Err(ResumeError::Batch {
+            failed,
+            total: report.get_entries().len(),
+        }),
+    }
+}
+
+fn score(
+    cv: &CV,
+    word_cloud: &WordCloud,
+    ranking: RankingStrategy,
+    explain: bool,
+) -> Result<(), ResumeError> {
+    let resume = cv.generate_resume_with_strategy(word_cloud, ranking);
+
+    if explain {
+        print!(
+            "{}",
+            String::from_utf8_lossy(&render_debug_from_resume(&resume)?)
+        );
+        return Ok(());
+    }
+
+    let mut total = 0.0;
+
+    println!("Skills:");
+    for (category, skills) in resume.get_skills() {
+        println!("    {}", category);
+        for skill in skills {
+            println!(
+                "        {:>8.3}  {}",
+                skill.get_point_value(),
+                skill.sortable
+            );
+            total += skill.get_point_value();
+        }
+    }
+
+    println!("Work Experience:");
+    for experience in resume.get_work_experience() {
+        println!(
+            "    {:>8.3}  {}",
+            experience.get_point_value(),
+            experience.sortable.get_job_title()
+        );
+        total += experience.get_point_value();
+    }
+
+    println!("Education:");
+    for education in resume.get_education() {
+        println!(
+            "    {:>8.3}  {}",
+            education.get_point_value(),
+            education.sortable.get_school_name()
+        );
+        total += education.get_point_value();
+    }
+
+    println!("Projects:");
+    for project in resume.get_projects() {
+        println!(
+            "    {:>8.3}  {}",
+            project.get_point_value(),
+            project.sortable.get_project_name()
+        );
+        total += project.get_point_value();
+    }
+
+    println!("Total: {:.3}", total);
+
+    Ok(())
+}
+
+fn gap(cv: &CV, word_cloud: &WordCloud) -> Result<(), ResumeError> {
+    print!("{}", GapReport::new(cv, word_cloud));
+
+    Ok(())
+}
+
+fn experience(cv: &CV, job: &Path, word_cloud: &WordCloud) -> Result<(), ResumeError> {
+    print!(
+        "{}",
+        ExperienceReport::from_job_file(cv, path_string(job), word_cloud.get_tokenizer())?
+    );
+
+    Ok(())
+}
+
+fn suggest_skills(
+    mut cv: CV,
+    taxonomy: &Path,
+    save: Option<&Path>,
+    word_cloud: &WordCloud,
+) -> Result<(), ResumeError> {
+    let taxonomy = Taxonomy::load_from_file(path_string(taxonomy))?;
+
+    let suggestions = SkillSuggestions::new(&cv, &taxonomy, word_cloud.get_tokenizer());
+    print!("{}", suggestions);
+
+    if let Some(save) = save {
+        suggestions.add_to(&mut cv);
+        cv.save_to_file(path_string(save))?;
+        println!("Wrote {}", save.display());
+    }
+
+    Ok(())
+}
+
+fn cloud(word_cloud: &WordCloud, cv: Option<&CV>, out: &Path) -> Result<(), ResumeError> {
+    write_word_cloud_svg(word_cloud, cv, path_string(out))?;
+    println!("Wrote {}", out.display());
+
+    Ok(())
+}
+
+fn validate(cv: &Path) -> Result<(), ResumeError> {
+    let cv_file = load_cv(cv, &Synonyms::builtin())?;
+    println!("{}", cv_file);
+    println!("{} is a valid CV", cv.display());
+
+    Ok(())
+}
@@ -1,277 +1,113 @@
+use crate::error::{Result, ResumeError};
+use crate::renderer::ResumeRenderer;
 use crate::resume::*;
 use std::fs;
-// use std::io::prelude::*;
-
+use std::io::Write;
 
+// Renders a resume as a markdown document
+pub struct MarkdownRenderer {
+    contents: String,
+}
 
-// Helper capitalize funciton
-#[allow(unused)]
-fn capitalize(string: String) -> String {
-    let mut output = String::new();
-    let mut first = true;
+impl Default for MarkdownRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-    for character in string.chars() {
-        if first {
-            output.push(character.to_ascii_uppercase());
-            first = false;
-        } else {
-            output.push(character);
+impl MarkdownRenderer {
+    pub fn new() -> Self {
+        Self {
+            contents: String::new(),
         }
     }
-
-    output
 }
 
+impl ResumeRenderer for MarkdownRenderer {
+    fn write_section_header(&mut self, text: &str) -> Result<()> {
+        self.contents.push_str(&format!("# {}\n", text));
+        Ok(())
+    }
 
-#[allow(unused)]
-pub fn generate_markdown_from_resume(resume: &Resume, output_file: String) {
-//    let mut file = File::create("./res/test.md").unwrap();
-    let mut contents = String::new();
-    
-    
-    let mut write_section_header = |c: &mut String, text: &str| {
-        c.push_str(&format!("# {}\n", text.to_string()));
-    };
-    
-    let mut write_item_header = |c: &mut String, text: &str| {
-        c.push_str(&format!("## {}\n", text.to_string()));
-    };
-    
-
-    let mut write_normal = |c: &mut String, text: &str| {
-        c.push_str(&format!("{}\n", text.to_string()));
-    };
-
-    let mut write_bullets = |c: &mut String, text: &Vec<String>| {
-        for item in text {
-            c.push_str(&format!("* {}\n", item));
-        }
-    };
-    
-    // Name
-    write_section_header(&mut contents, {
-        match resume.get_name() {
-            Some(name) => &name,
-            None => ""
-        }
-    });
+    fn write_item_header(&mut self, text: &str) -> Result<()> {
+        self.contents.push_str(&format!("## {}\n", text));
+        Ok(())
+    }
 
-    // Linked in \ website | phone number
-    write_normal(
-        &mut contents,
-        &format!("{} | {} | {}",
-            match resume.get_linked_in() {
-                Some(linked_in) => { linked_in },
-                None => { "" }
-            },
-            match resume.get_website() {
-                Some(website) => { website },
-                None => { "" }
-            },
-            match resume.get_phone_number() {
-                Some(phone_number) => { format!("{}", phone_number) },
-                None => { "".to_string() }
-            }
-        )
-    );
+    fn write_extra_info(&mut self, text: &str) -> Result<()> {
+        self.contents.push_str(&format!("*{}*\n\n", text));
+        Ok(())
+    }
 
-    // Skills Heading
-    write_section_header(&mut contents, "Skills");
-    
-    // Add Skills
-    for category in resume.get_skills().iter() {
-        write_item_header(&mut contents, &capitalize(category.0.clone()));
+    fn write_normal(&mut self, text: &str) -> Result<()> {
+        self.contents.push_str(&format!("{}\n\n", text));
+        Ok(())
+    }
 
-        let mut skills = String::new();
-        for item in category.1.iter() {
-            skills.push_str(&format!("{} ", item.sortable));
+    fn write_bullets(&mut self, items: &[String]) -> Result<()> {
+        for item in items {
+            self.contents.push_str(&format!("* {}\n", item));
         }
-
-        write_normal(&mut contents, &skills);
+        self.contents.push('\n');
+        Ok(())
     }
-    
-
-    // Add Work experience
-    write_section_header(&mut contents, "Work Experience");
-
-    for experience in resume.get_work_experience().iter() {
-        let e = &experience.sortable;
-        
-        // Work experience title
-        write_item_header(
-           &mut contents,
-           &format!("{} {}", e.get_job_title(), {
-                let mut o = String::new();
 
-                let start_date = e.get_job_start();
-                let end_date = e.get_job_end();
-
-                o.push_str(&format!("{}", start_date));
-                match end_date {
-                    Some(date) => o.push_str(&format!("-{}", date)),
-                    None => {}
-                }
-
-                o
-           }),
-        );
-        
-        // Work experience company
-        write_normal(
-            &mut contents,
-            &format!("{} {}", e.get_company_name(), {
-                let mut o = String::new();
-                match e.get_job_location() {
-                    Some(location) => o = format!("{}", location),
-                    None => {}
-                }
-                o
-            })
-        );
-        
-        // Job description
-        write_bullets(
-            &mut contents,
-            e.get_job_description()
-        );
+    fn finish(self) -> Result<Vec<u8>> {
+        Ok(self.contents.into_bytes())
     }
+}
 
-    
-    // Add Education
-    write_section_header(&mut contents, "Education");
-
-    for education in resume.get_education() {
-        let e = &education.sortable;
-
-        // School and dates
-        write_item_header(
-            &mut contents,
-            &format!("{} {}", e.get_school_name(), {
-                let mut o = String::new();
-
-                let start_date = e.get_education_start();
-                let end_date = e.get_education_end();
-
-                o.push_str(&format!("{}", start_date));
-                match end_date {
-                    Some(date) => o.push_str(&format!("- {}", date)),
-                    None => {}
-                }
-
-                o
-            })
-        );
-
-        // Major and location
-        write_normal(
-            &mut contents,
-            &format!("{} {}", {
-                let majors = e.get_major();
-                let mut o = String::new();
-
-                for (index, major) in majors.into_iter().enumerate() {
-                    if index != majors.len() - 1 {
-                        o.push_str(&format!("{} and", major));
-                    } else {
-                        o.push_str(&format!("{}", major));
-                    }
-                }
-
-                o
-            }, {
-                let mut o = String::new();
-
-                o.push_str(&format!("{}", e.get_education_start()));
-
-                match e.get_education_end() {
-                    Some(date) => o.push_str(&format!(" - {}", date)),
-                    None => {}
-                }
-
-                o
-            })
-        );
-
-        // Minor(s)
-        write_normal(
-            &mut contents, 
-            &format!("Minor in {}", {
-                let minors = e.get_minor();
-                let mut o = String::new();
-
-                
-                for (index, minor) in minors.into_iter().enumerate() {
-                    if index != minors.len() - 1 {
-                        o.push_str(&format!("{} and ", minor));
-                    } else {
-                        o.push_str(&format!("{}", minor));
-                    }
-                }
-
-                o
-            })
-        );
-
-        // Relavant coursework
-        write_normal(
-            &mut contents,
-            &format!("* Relevant Coursework: {}", {
-                let mut o = String::new();
-                let courses = e.get_coursework();
+// Writes the markdown resume to a file
+pub fn generate_markdown_from_resume(resume: &Resume, output_file: String) -> Result<()> {
+    let contents = render_markdown_from_resume(resume)?;
 
-                for (index, course) in courses.into_iter().enumerate() {
-                    if index != courses.len() {
-                        o.push_str(&format!("{}, ", course));
-                    } else {
-                        o.push_str(&format!("{}", course));
-                    }
-                }
+    fs::write(&output_file, contents).map_err(|error| ResumeError::io(output_file, error))
+}
 
-                o
-            })
-        );
+// Writes the markdown resume to any writer
+pub fn write_markdown_from_resume<W: Write>(resume: &Resume, writer: &mut W) -> Result<()> {
+    let contents = render_markdown_from_resume(resume)?;
 
-        // GPA
-        write_normal(
-            &mut contents,
-            &format!("* {} GPA", e.get_gpa())
-        );
+    // The writer has no file name, but it is still an io error so it keeps its source
+    writer
+        .write_all(&contents)
+        .map_err(|error| ResumeError::io("<writer>", error))
+}
 
+// Renders the markdown resume in memory
+pub fn render_markdown_from_resume(resume: &Resume) -> Result<Vec<u8>> {
+    let mut renderer = MarkdownRenderer::new();
+    renderer.render_resume(resume)?;
+    renderer.finish()
+}
 
-        // Projects
-        write_section_header(&mut contents, "Projects");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_cloud::WordCloud;
+    use std::{error::Error, io};
 
-        for project in resume.get_projects().iter() {
-            let e = &project.sortable;
+    struct FailingWriter;
 
-            // Project title and dates
-            write_item_header(
-                &mut contents,
-                &format!("{} {}",
-                    e.get_project_name(),
-                    {
-                        let mut o = String::new();
+    impl Write for FailingWriter {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+        }
 
-                        let start_date = e.get_project_start();
-                        let end_date = e.get_project_end();
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
 
-                        o.push_str(&format!("{}", start_date));
-                        match end_date {
-                            Some(date) => o.push_str(&format!(" - {}", date)),
-                            None => {}
-                        }
+    #[test]
+    fn writer_errors_are_io_errors() {
+        let mut cv = CV::new();
+        cv.set_name("Ada Lovelace".to_string());
+        let resume = cv.generate_resume(&WordCloud::new());
 
-                        o
-                    }
-                )
-            );
+        let error = write_markdown_from_resume(&resume, &mut FailingWriter).unwrap_err();
 
-            // Project descriptions
-            write_bullets(&mut contents,
-                e.get_project_description()        
-            );
-        }
+        assert!(matches!(error, ResumeError::Io { .. }));
+        assert!(error.source().is_some());
     }
-   
-    fs::write(output_file , contents).expect("Unable to write file");
 }
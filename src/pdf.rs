@@ -1,13 +1,12 @@
+use crate::error::{Result, ResumeError};
+use crate::renderer::ResumeRenderer;
 use crate::resume::*;
-use glyph_brush_layout::ab_glyph::Font;
+use glyph_brush_layout::ab_glyph::{Font, FontRef, ScaleFont};
 use glyph_brush_layout::GlyphPositioner;
 use itertools::Itertools;
 use printpdf::*;
-use std::fs::File;
-use std::io::BufWriter;
-
-use std::io::Read;
-use std::io::Write;
+use std::fs::{self, File};
+use std::io::{Read, Write};
 
 enum FontStyle {
     SectionHeader,
@@ -43,8 +42,9 @@ const BLACK: printpdf::Color = Color::Rgb({
 impl FontStyle {
     fn get_font_size(&self) -> f32 {
         match self {
-            FontStyle::SectionHeader | FontStyle::ItemHeader => 25.0,
-            FontStyle::ExtraInfo | FontStyle::Normal => 14.0,
+            FontStyle::SectionHeader => 20.0,
+            FontStyle::ItemHeader => 14.0,
+            FontStyle::ExtraInfo | FontStyle::Normal => 12.0,
         }
     }
 
@@ -54,24 +54,35 @@ impl FontStyle {
             _ => BLACK,
         }
     }
-}
 
-fn capitalize(string: String) -> String {
-    let mut output = String::new();
-    let mut first = true;
-
-    for character in string.chars() {
-        if first {
-            output.push(character.to_ascii_uppercase());
-            first = false;
-        } else {
-            output.push(character);
+    // Index into the loaded fonts, 0 is normal, 1 is bold and 2 is italic
+    fn get_font_id(&self) -> usize {
+        match self {
+            FontStyle::SectionHeader | FontStyle::ItemHeader => 1,
+            FontStyle::ExtraInfo => 2,
+            FontStyle::Normal => 0,
         }
     }
-
-    output
 }
 
+const NORMAL_FONT_FILE: &str = "./fonts/times_new_roman.ttf";
+const BOLD_FONT_FILE: &str = "./fonts/times_new_roman_bold.ttf";
+const ITALIC_FONT_FILE: &str = "./fonts/times new roman italic.ttf";
+
+// Page layout in mm, letter size
+const PAGE_WIDTH: f32 = 215.9;
+const PAGE_HEIGHT: f32 = 279.4;
+const TOP_MARGIN: f32 = 15.0;
+const BOTTOM_MARGIN: f32 = 15.0;
+const LEFT_MARGIN: f32 = 20.0;
+const TEXT_WIDTH: f32 = PAGE_WIDTH - 2.0 * LEFT_MARGIN;
+
+// Space above section and item headers and how far bullets are indented, in mm
+const SECTION_SPACING: f32 = 4.0;
+const ITEM_SPACING: f32 = 2.0;
+const BULLET_INDENT: f32 = 3.0;
+const BULLET_TEXT_INDENT: f32 = 7.0;
+
 fn mm_to_px(mm: f32) -> f32 {
     mm * 96.0 / 25.4
 }
@@ -80,55 +91,138 @@ fn px_to_mm(px: f32) -> f32 {
     px * 25.4 / 96.0
 }
 
-pub fn create_pdf_from_resume(resume: &Resume) {
-    // Create a pdf letter size
-    let (doc, page1, layer1) = PdfDocument::new("test", Mm(215.9), Mm(279.4), "Layer 1");
+// Renders a resume as a pdf, laying text out with glyph_brush_layout so it wraps within the page
+pub struct PdfRenderer<'a> {
+    doc: PdfDocumentReference,
+    current_layer: PdfLayerReference,
+
+    // Fonts used for layout and the same fonts embedded in the document, indexed by font id
+    gbl_fonts: [FontRef<'a>; 3],
+    writing_fonts: [IndirectFontRef; 3],
+
+    current_line_height: f32,
+    page_count: usize,
+
+    // Headers waiting for the content under them, and the headers of the content being
+    // written so they can be repeated when it continues on a new page
+    pending: Vec<Block>,
+    current_section: Option<String>,
+    current_item: Option<String>,
+}
+
+impl<'a> PdfRenderer<'a> {
+    pub fn new(normal_font: &'a [u8], bold_font: &'a [u8], italic_font: &'a [u8]) -> Result<Self> {
+        // Create a pdf letter size
+        let (doc, page1, layer1) =
+            PdfDocument::new("Resume", Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+
+        let current_layer = doc.get_page(page1).get_layer(layer1);
+
+        let font_data = [
+            (NORMAL_FONT_FILE, normal_font),
+            (BOLD_FONT_FILE, bold_font),
+            (ITALIC_FONT_FILE, italic_font),
+        ];
+
+        // Load the font reference for glyph brush layout
+        let [normal, bold, italic] = font_data.map(|(filename, data)| {
+            FontRef::try_from_slice(data).map_err(|error| ResumeError::font(filename, error))
+        });
+        let gbl_fonts = [normal?, bold?, italic?];
+
+        let [normal, bold, italic] = font_data.map(|(filename, data)| {
+            doc.add_external_font(data)
+                .map_err(|error| ResumeError::font(filename, error))
+        });
+        let writing_fonts = [normal?, bold?, italic?];
+
+        Ok(Self {
+            doc,
+            current_layer,
+            gbl_fonts,
+            writing_fonts,
+            current_line_height: PAGE_HEIGHT - TOP_MARGIN,
+            page_count: 1,
+            pending: Vec::new(),
+            current_section: None,
+            current_item: None,
+        })
+    }
 
-    let current_layer = doc.get_page(page1).get_layer(layer1);
+    // Adds vertical space unless we are already at the top of the page
+    fn add_spacing(&mut self, spacing: f32) {
+        if !self.at_top_of_page() {
+            self.current_line_height -= spacing;
+        }
+    }
 
-    // Fonts
-    // Load in the font data
-    let normal_font = {
-        let mut font_file = File::open("./fonts/times_new_roman.ttf").unwrap();
-        let mut font_data = Vec::with_capacity(font_file.metadata().unwrap().len() as usize);
-        font_file.read_to_end(&mut font_data).unwrap();
-        font_data
-    };
+    fn at_top_of_page(&self) -> bool {
+        self.current_line_height >= PAGE_HEIGHT - TOP_MARGIN
+    }
 
-    let bold_font = {
-        let mut font_file = File::open("./fonts/times_new_roman_bold.ttf").unwrap();
-        let mut font_data = Vec::with_capacity(font_file.metadata().unwrap().len() as usize);
-        font_file.read_to_end(&mut font_data).unwrap();
-        font_data
-    };
+    // Writes out any headers that never got content
+    pub fn flush(&mut self) -> Result<()> {
+        self.write_block(None)
+    }
 
-    // Load the font reference for glyph brush layout
-    let gbl_normal_font = glyph_brush_layout::ab_glyph::FontRef::try_from_slice(&normal_font).unwrap();
-    let gbl_bold_font = glyph_brush_layout::ab_glyph::FontRef::try_from_slice(&bold_font).unwrap();
-    let gbl_fonts = &[gbl_normal_font, gbl_bold_font];
+    // Number of pages the resume has taken so far
+    pub fn get_page_count(&self) -> usize {
+        self.page_count
+    }
 
-    let normal_writing_font = doc.add_external_font(normal_font.as_slice()).unwrap();
-    let bold_writing_font = doc.add_external_font(bold_font.as_slice()).unwrap();
+    // Starts a new page and moves the current line to the top of it
+    fn new_page(&mut self) {
+        let (page, layer) = self
+            .doc
+            .add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        self.current_layer = self.doc.get_page(page).get_layer(layer);
+        self.current_line_height = PAGE_HEIGHT - TOP_MARGIN;
+        self.page_count += 1;
+    }
 
-    let mut current_line_height = 269.4;
+    // Wraps the text to the width and splits it into lines,
+    // returns each line with its baseline in px and the total height of the text in mm
+    fn layout_text(
+        &self,
+        text_to_write: &str,
+        width: f32,
+        style: &FontStyle,
+    ) -> Result<(Vec<(f32, String)>, f32)> {
+        // Line breaks and tabs are laid out without a glyph, which would throw off the
+        // glyph to character mapping below, so every run of whitespace becomes one space
+        let normalized = text_to_write.split_whitespace().join(" ");
+        let text_to_write = normalized.as_str();
+
+        let font_id = style.get_font_id();
+        let scale = self.gbl_fonts[font_id]
+            .pt_to_px_scale(style.get_font_size())
+            .ok_or_else(|| ResumeError::layout(text_to_write, "font has no units per em"))?;
 
-    let mut write_to_page = |starting_height: f32, text_to_write: &str, left_margin: f32, width: f32, font_size: f32, font_id: usize| -> f32 {
         // calculate the glyph positions using glyph_brush_layout
         let glyphs = glyph_brush_layout::Layout::default().calculate_glyphs(
-            gbl_fonts,
+            &self.gbl_fonts,
             &glyph_brush_layout::SectionGeometry {
                 bounds: (mm_to_px(width), f32::INFINITY),
                 ..Default::default()
             },
             &[glyph_brush_layout::SectionText {
                 text: text_to_write,
-                scale: gbl_fonts[font_id].pt_to_px_scale(font_size).unwrap(),
+                scale,
                 font_id: glyph_brush_layout::FontId(font_id),
             }],
         );
 
         // make sure the number of glyphs matches the number of chars in the sample text
-        assert_eq!(glyphs.len(), text_to_write.chars().count());
+        if glyphs.len() != text_to_write.chars().count() {
+            return Err(ResumeError::layout(
+                text_to_write,
+                format!(
+                    "{} glyphs were laid out for {} characters",
+                    glyphs.len(),
+                    text_to_write.chars().count()
+                ),
+            ));
+        }
 
         // group the glyphs by y position
         let line_starts = glyphs
@@ -139,24 +233,12 @@ pub fn create_pdf_from_resume(resume: &Resume) {
             .map(|(y, mut group)| (y, group.next().unwrap().0))
             .collect::<Vec<_>>();
 
-        // get the minimum y position
-        let min = glyphs
-            .iter()
-            .map(|glyph| glyph.glyph.position.y)
-            .fold(f32::INFINITY, |a, b| a.min(b));
-
         // need a peekable iterator so we can see where the next line starts
         let mut iter = line_starts.iter().peekable();
+        let mut lines = Vec::new();
 
-        let mut line_diff = 0.0;
-
-        // iterate over the line_starts and draw the text
-        loop {
-            // get the next line start, if there is none then we break out of the loop
-            let Some((y, start)) = iter.next() else {
-                break;
-            };
-
+        // get the next line start, if there is none then we break out of the loop
+        while let Some((y, start)) = iter.next() {
             // peek into the line start after that to get the end index,
             // if there is none (we're at the last line of the loop) then we use the length of the sample text
             let end = if let Some((_, end)) = iter.peek() {
@@ -166,247 +248,303 @@ pub fn create_pdf_from_resume(resume: &Resume) {
             };
 
             // Slice up the text
-            // if you know you're only dealing with ASCII characters you can simplify this as
-            // `let line = &sample[*start..end];`
-            // which saves on an allocation to a String;
-            // or you can use char_indices to get the byte indices and slice that way
             let line = text_to_write
                 .chars()
                 .skip(*start)
                 .take(end - start)
                 .collect::<String>();
 
-            let font = match font_id {
-                1 => {
-                    &bold_writing_font
-                } 
-                _ => {
-                    &normal_writing_font
-                }            
-            };
+            lines.push((*y, line));
+        }
+
+        // y is the baseline of the last line so add its descent and the gap to the next line
+        let height = match line_starts.last() {
+            Some((y, _)) => {
+                let scaled_font = self.gbl_fonts[font_id].as_scaled(scale);
+                px_to_mm(*y - scaled_font.descent() + scaled_font.line_gap())
+            }
+            None => 0.0,
+        };
+
+        Ok((lines, height))
+    }
+
+    // Draws laid out lines below the current line
+    fn draw_lines(&self, lines: &[(f32, String)], left_margin: f32, style: &FontStyle) {
+        self.current_layer.set_fill_color(style.get_font_color());
 
-            current_layer.use_text(
+        for (y, line) in lines {
+            self.current_layer.use_text(
                 line.trim(),
-                font_size,
+                style.get_font_size(),
                 Mm(left_margin),
-                Mm(starting_height + px_to_mm(min) - px_to_mm(*y)),
-                font,
+                Mm(self.current_line_height - px_to_mm(*y)),
+                &self.writing_fonts[style.get_font_id()],
             );
         }
+    }
 
-        // let mut outs = line_starts.iter().rev();
-        // let binding = (font_size, 0);
-        // let (_, (output_val, _)) = (outs.next(), outs.next().unwrap_or(&binding));
-        // px_to_mm(*output_val)
-        px_to_mm(line_starts.last().unwrap().0)
-    };
-
-    // Add Skills
-    // let sample = "This is a test of the word wrapping technology This is a test of the word wrapping technology This is a test of the word wrapping technology This is a test of the word wrapping technology This is a test of the word wrapping technology This is a test of the word wrapping technology This is a test of the word wrapping technology This is a test of the word wrapping technology ";
-    // current_line_height -= write_to_page(current_line_height, sample, 20.0, 160.0, 14.0, 0);
-    // current_line_height -= write_to_page(current_line_height, sample, 20.0, 160.0, 25.0, 0);
-    current_line_height -= write_to_page(current_line_height, "Skills", 20.0, 160.0, 25.0, 1);
-
-    for category in resume.get_skills().iter() {
-        current_line_height -= write_to_page(current_line_height, &capitalize(category.0.clone()), 20.0, 160.0, 25.0, 1);
-
-        let mut skills = String::new();
-        for item in category.1.iter() {
-            skills.push_str(&std::format!("{} ", item.sortable));
-        }
-        current_line_height -= write_to_page(current_line_height, &skills, 20.0, 160.0, 14.0, 0);
+    // Height the block takes up on the page, including the space above it
+    fn block_height(&self, block: &Block) -> Result<f32> {
+        let height = if block.bullet {
+            let (_, bullet_height) =
+                self.layout_text("•", BULLET_TEXT_INDENT - BULLET_INDENT, &block.style)?;
+            let (_, text_height) =
+                self.layout_text(&block.text, TEXT_WIDTH - BULLET_TEXT_INDENT, &block.style)?;
+            bullet_height.max(text_height)
+        } else {
+            self.layout_text(&block.text, TEXT_WIDTH, &block.style)?.1
+        };
+
+        Ok(block.spacing + height)
     }
 
+    // Draws the block at the current line and moves the current line down past it
+    fn draw_block(&mut self, block: &Block) -> Result<()> {
+        self.add_spacing(block.spacing);
+
+        let height = if block.bullet {
+            // Draw the bullet and the item side by side so wrapped lines hang under the text
+            let (bullet, bullet_height) =
+                self.layout_text("•", BULLET_TEXT_INDENT - BULLET_INDENT, &block.style)?;
+            let (lines, text_height) =
+                self.layout_text(&block.text, TEXT_WIDTH - BULLET_TEXT_INDENT, &block.style)?;
+            self.draw_lines(&bullet, LEFT_MARGIN + BULLET_INDENT, &block.style);
+            self.draw_lines(&lines, LEFT_MARGIN + BULLET_TEXT_INDENT, &block.style);
+            bullet_height.max(text_height)
+        } else {
+            let (lines, height) = self.layout_text(&block.text, TEXT_WIDTH, &block.style)?;
+            self.draw_lines(&lines, LEFT_MARGIN, &block.style);
+            height
+        };
 
-    /*
-    // Add Work Experience
-    // current_layer.set_font(&font, FontStyle::SectionHeader.get_font_size()); // Section Header
-    // current_layer.set_fill_color(FontStyle::SectionHeader.get_font_color());
-    // current_layer.write_text("Work Experience", &font);
-    write_section_header(&current_layer, "Work Experience");
-
-    for experience in resume.get_work_experience().iter() {
-        let e = &experience.sortable;
-        // Job title and dates
-        current_layer.set_line_height(FontStyle::ItemHeader.get_font_size());
-        current_layer.add_line_break();
-        current_layer.set_font(&font_bold, FontStyle::ItemHeader.get_font_size());
-        current_layer.set_fill_color(FontStyle::ItemHeader.get_font_color());
-        current_layer.write_text(
-            format!("{} {}", e.get_job_title(), {
-                let mut o = String::new();
-
-                let start_date = e.get_job_start();
-                let end_date = e.get_job_end();
-
-                o.push_str(&format!("{}", start_date));
-                match end_date {
-                    Some(date) => o.push_str(&format!("-{}", date)),
-                    None => {}
-                }
+        self.current_line_height -= height;
 
-                o
-            }),
-            &font_bold,
-        );
+        Ok(())
+    }
+
+    // Draws the headers waiting on content together with the first block of content.
+    // If they do not fit on the rest of the page they all move to a new page, and if the
+    // page breaks in the middle of a section the section and item headers are repeated
+    fn write_block(&mut self, block: Option<Block>) -> Result<()> {
+        let mut blocks = std::mem::take(&mut self.pending);
+        blocks.extend(block);
+
+        let mut height = 0.0;
+        for block in blocks.iter() {
+            height += self.block_height(block)?;
+        }
 
-        // Company and location
-        current_layer.set_line_height(FontStyle::ExtraInfo.get_font_size());
-        current_layer.add_line_break();
-        current_layer.set_font(&font, FontStyle::ExtraInfo.get_font_size());
-        current_layer.set_fill_color(FontStyle::ExtraInfo.get_font_color());
-        current_layer.write_text(
-            format!("{} {}", e.get_company_name(), {
-                let mut o = String::new();
-                match e.get_job_location() {
-                    Some(location) => o = format!("{}", location),
-                    None => {}
+        if self.current_line_height - height < BOTTOM_MARGIN && !self.at_top_of_page() {
+            self.new_page();
+
+            let has_section_header = blocks
+                .iter()
+                .any(|block| matches!(block.style, FontStyle::SectionHeader));
+            let has_item_header = blocks
+                .iter()
+                .any(|block| matches!(block.style, FontStyle::ItemHeader));
+
+            let mut continuation = Vec::new();
+            if !has_section_header {
+                if let Some(section) = &self.current_section {
+                    continuation.push(Block::section_header(&format!("{} (continued)", section)));
                 }
-                o
-            }),
-            &font,
-        );
+            }
+            if !has_section_header && !has_item_header {
+                if let Some(item) = &self.current_item {
+                    continuation.push(Block::item_header(&format!("{} (continued)", item)));
+                }
+            }
+            blocks.splice(0..0, continuation);
+        }
 
-        // Job descriptions
-        // How is this the easiet one to implement -_-
-        current_layer.set_line_height(FontStyle::Normal.get_font_size());
-        current_layer.set_font(&font, FontStyle::Normal.get_font_size());
-        current_layer.set_fill_color(FontStyle::Normal.get_font_color());
-        for desc in e.get_job_description() {
-            current_layer.add_line_break();
-            current_layer.write_text(format!("* {}", desc.clone()), &font);
+        for block in blocks.iter() {
+            self.draw_block(block)?;
         }
+
+        Ok(())
     }
+}
 
-    // Add Education
-    current_layer.set_line_height(FontStyle::SectionHeader.get_font_size());
-    current_layer.add_line_break();
-    // current_layer.set_font(&font, FontStyle::SectionHeader.get_font_size()); // Section Header
-    // current_layer.set_fill_color(FontStyle::SectionHeader.get_font_color());
-    // current_layer.write_text("Education", &font);
-    write_section_header(&current_layer, "Education");
-    for education in resume.get_education() {
-        let e = &education.sortable;
-
-        // School and dates
-        current_layer.set_line_height(FontStyle::ItemHeader.get_font_size());
-        current_layer.add_line_break();
-        current_layer.set_font(&font_bold, FontStyle::ItemHeader.get_font_size());
-        current_layer.set_fill_color(FontStyle::ItemHeader.get_font_color());
-        current_layer.write_text(
-            format!("{} {}", e.get_school_name(), {
-                let mut o = String::new();
-
-                let start_date = e.get_education_start();
-                let end_date = e.get_education_end();
-
-                o.push_str(&format!("{}", start_date));
-                match end_date {
-                    Some(date) => o.push_str(&format!("-{}", date)),
-                    None => {}
-                }
+// A piece of text waiting to be laid out on the page
+struct Block {
+    text: String,
+    style: FontStyle,
+    spacing: f32,
+    bullet: bool,
+}
 
-                o
-            }),
-            &font_bold,
-        );
+impl Block {
+    fn section_header(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            style: FontStyle::SectionHeader,
+            spacing: SECTION_SPACING,
+            bullet: false,
+        }
+    }
 
-        // Major and location
-        current_layer.set_line_height(FontStyle::ExtraInfo.get_font_size());
-        current_layer.add_line_break();
-        current_layer.set_font(&font, FontStyle::ExtraInfo.get_font_size());
-        current_layer.set_fill_color(FontStyle::ExtraInfo.get_font_color());
-        current_layer.write_text(
-            format!(
-                "{} {}",
-                {
-                    let majors = e.get_major();
-                    let mut o = String::new();
-
-                    for (index, major) in majors.into_iter().enumerate() {
-                        if index != majors.len() {
-                            o.push_str(&format!("{} and", major));
-                        } else {
-                            o.push_str(&format!("{}", major));
-                        }
-                    }
-
-                    o
-                },
-                {
-                    let mut o = String::new();
-
-                    o.push_str(&format!("{}", e.get_education_start()));
-
-                    match e.get_education_end() {
-                        Some(date) => o.push_str(&format!("-{}", date)),
-                        None => {}
-                    }
-
-                    o
-                }
-            ),
-            &font,
-        );
+    fn item_header(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            style: FontStyle::ItemHeader,
+            spacing: ITEM_SPACING,
+            bullet: false,
+        }
+    }
 
-        // Minor(s)
-        current_layer.set_line_height(FontStyle::ExtraInfo.get_font_size());
-        current_layer.add_line_break();
-        current_layer.set_font(&font, FontStyle::ExtraInfo.get_font_size());
-        current_layer.set_fill_color(FontStyle::ExtraInfo.get_font_color());
-        current_layer.write_text(
-            format!("Minor in {}", {
-                let minors = e.get_minor();
-                let mut o = String::new();
-
-                for (index, minor) in minors.into_iter().enumerate() {
-                    if index != minors.len() {
-                        o.push_str(&format!("{} and", minor));
-                    } else {
-                        o.push_str(&format!("{}", minor));
-                    }
-                }
+    fn extra_info(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            style: FontStyle::ExtraInfo,
+            spacing: 0.0,
+            bullet: false,
+        }
+    }
 
-                o
-            }),
-            &font,
-        );
+    fn normal(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            style: FontStyle::Normal,
+            spacing: 0.0,
+            bullet: false,
+        }
+    }
 
-        // Relevant coursework
-        current_layer.set_line_height(FontStyle::Normal.get_font_size());
-        current_layer.add_line_break();
-        current_layer.set_font(&font, FontStyle::Normal.get_font_size());
-        current_layer.set_fill_color(FontStyle::Normal.get_font_color());
-        current_layer.write_text(
-            format!("* Relevant Coursework {}", {
-                let mut o = String::new();
-                let courses = e.get_coursework();
-
-                for (index, course) in courses.into_iter().enumerate() {
-                    if index != courses.len() {
-                        o.push_str(&format!("{}, ", course));
-                    } else {
-                        o.push_str(&format!("{}", course));
-                    }
-                }
+    fn bullet(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            style: FontStyle::Normal,
+            spacing: 0.0,
+            bullet: true,
+        }
+    }
+}
 
-                o
-            }),
-            &font,
-        );
+impl ResumeRenderer for PdfRenderer<'_> {
+    // Headers are held back until the content under them is written so they are never
+    // left alone at the bottom of a page
+    fn write_section_header(&mut self, text: &str) -> Result<()> {
+        self.current_section = Some(text.to_string());
+        self.current_item = None;
+        self.pending.push(Block::section_header(text));
+        Ok(())
+    }
+
+    fn write_item_header(&mut self, text: &str) -> Result<()> {
+        self.current_item = Some(text.to_string());
+        self.pending.push(Block::item_header(text));
+        Ok(())
+    }
 
-        // GPA
-        current_layer.set_line_height(FontStyle::Normal.get_font_size());
-        current_layer.add_line_break();
-        current_layer.set_font(&font, FontStyle::Normal.get_font_size());
-        current_layer.set_fill_color(FontStyle::Normal.get_font_color());
-        current_layer.write_text(format!("* {} GPA", e.get_gpa()), &font);
+    fn write_extra_info(&mut self, text: &str) -> Result<()> {
+        self.pending.push(Block::extra_info(text));
+        Ok(())
     }
-    */
 
-    // current_layer.end_text_section();
+    fn write_normal(&mut self, text: &str) -> Result<()> {
+        self.write_block(Some(Block::normal(text)))
+    }
+
+    fn write_bullets(&mut self, items: &[String]) -> Result<()> {
+        for item in items {
+            self.write_block(Some(Block::bullet(item)))?;
+        }
+        Ok(())
+    }
 
-    // Save the pdf in the designated directory
-    doc.save(&mut BufWriter::new(File::create("./res/test.pdf").unwrap()))
-        .unwrap();
+    fn finish(mut self) -> Result<Vec<u8>> {
+        self.flush()?;
+
+        self.doc
+            .save_to_bytes()
+            .map_err(|error| ResumeError::render("pdf", error))
+    }
+}
+
+// The font data the pdf is rendered with, loaded once so many renderers can share it
+pub struct PdfFonts {
+    normal_font: Vec<u8>,
+    bold_font: Vec<u8>,
+    italic_font: Vec<u8>,
+}
+
+impl PdfFonts {
+    pub fn load() -> Result<Self> {
+        Ok(Self {
+            normal_font: load_font(NORMAL_FONT_FILE)?,
+            bold_font: load_font(BOLD_FONT_FILE)?,
+            italic_font: load_font(ITALIC_FONT_FILE)?,
+        })
+    }
+
+    pub fn renderer(&self) -> Result<PdfRenderer<'_>> {
+        PdfRenderer::new(&self.normal_font, &self.bold_font, &self.italic_font)
+    }
+}
+
+// Reads the font data from a font file
+fn load_font(filename: &str) -> Result<Vec<u8>> {
+    let mut font_file = File::open(filename).map_err(|error| ResumeError::io(filename, error))?;
+    let mut font_data = Vec::new();
+    font_file
+        .read_to_end(&mut font_data)
+        .map_err(|error| ResumeError::io(filename, error))?;
+
+    Ok(font_data)
+}
+
+// Writes the pdf resume to a file
+pub fn create_pdf_from_resume(resume: &Resume, output_file: String) -> Result<()> {
+    let contents = render_pdf_from_resume(resume)?;
+
+    fs::write(&output_file, contents).map_err(|error| ResumeError::io(output_file, error))
+}
+
+// Writes the pdf resume to any writer
+pub fn write_pdf_from_resume<W: Write>(resume: &Resume, writer: &mut W) -> Result<()> {
+    let contents = render_pdf_from_resume(resume)?;
+
+    // The writer has no file name, but it is still an io error so it keeps its source
+    writer
+        .write_all(&contents)
+        .map_err(|error| ResumeError::io("<writer>", error))
+}
+
+// Renders the pdf resume in memory
+pub fn render_pdf_from_resume(resume: &Resume) -> Result<Vec<u8>> {
+    let fonts = PdfFonts::load()?;
+
+    let mut renderer = fonts.renderer()?;
+    renderer.render_resume(resume)?;
+    renderer.finish()
+}
+
+// Counts how many pages the resume takes when rendered as a pdf
+pub fn count_pdf_pages(resume: &Resume, fonts: &PdfFonts) -> Result<usize> {
+    let mut renderer = fonts.renderer()?;
+    renderer.render_resume(resume)?;
+    renderer.flush()?;
+
+    Ok(renderer.get_page_count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lays_out_text_with_line_breaks_and_tabs() {
+        let fonts = PdfFonts::load().unwrap();
+        let renderer = fonts.renderer().unwrap();
+
+        let (lines, height) = renderer
+            .layout_text("Built a\tparser\nin Rust", TEXT_WIDTH, &FontStyle::Normal)
+            .unwrap();
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].1, "Built a parser in Rust");
+        assert!(height > 0.0);
+    }
 }
@@ -0,0 +1,59 @@
+use crate::scorer::{sum_or_zero, Bm25, Scorer};
+use crate::word_cloud::WordCloud;
+use std::str::FromStr;
+
+// How resume text is scored against the word cloud when ranking items
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RankingStrategy {
+    // Sum of the weights of every word, longer text scores higher
+    RawSum,
+    // Sum of the weights divided by the total weight of the word cloud,
+    // ranks like RawSum but is comparable between job descriptions
+    Ratio,
+    // Sum of the weights divided by the number of words in the text
+    #[default]
+    LengthNormalized,
+    // Weight of the single most relevant word in the text
+    MaxTerm,
+    // BM25, repeated words add less and less. Resumes are ranked with the
+    // average item length of the CV, see CV::create_bm25, scoring with this
+    // variant directly uses the default parameters
+    Bm25,
+}
+
+impl Scorer for RankingStrategy {
+    fn score(&self, word_cloud: &WordCloud, text: &str) -> f32 {
+        let weights = word_cloud.get_word_weights(text.to_string());
+
+        match self {
+            RankingStrategy::RawSum => sum_or_zero(weights),
+            RankingStrategy::Ratio => word_cloud
+                .get_word_score(text.to_string())
+                .get_score_ratio(),
+            RankingStrategy::LengthNormalized => {
+                if weights.is_empty() {
+                    0.0
+                } else {
+                    sum_or_zero(weights.iter().copied()) / weights.len() as f32
+                }
+            }
+            RankingStrategy::MaxTerm => weights.iter().copied().fold(0.0, f32::max),
+            RankingStrategy::Bm25 => Bm25::default().score(word_cloud, text),
+        }
+    }
+}
+
+impl FromStr for RankingStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "raw-sum" | "raw" => Ok(RankingStrategy::RawSum),
+            "ratio" => Ok(RankingStrategy::Ratio),
+            "length-normalized" | "length" => Ok(RankingStrategy::LengthNormalized),
+            "max-term" | "max" => Ok(RankingStrategy::MaxTerm),
+            "bm25" => Ok(RankingStrategy::Bm25),
+            other => Err(format!("unknown ranking strategy: {}", other)),
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::error::Result;
+use crate::resume::*;
+
+// Helper capitalize function
+pub fn capitalize(string: &str) -> String {
+    let mut output = String::new();
+    let mut first = true;
+
+    for character in string.chars() {
+        if first {
+            output.push(character.to_ascii_uppercase());
+            first = false;
+        } else {
+            output.push(character);
+        }
+    }
+
+    output
+}
+
+// Formats a start and optional end date as "start - end", an item with no end date is ongoing
+pub fn format_date_range(start: &Date, end: &Option<Date>) -> String {
+    match end {
+        Some(end) => format!("{} - {}", start, end),
+        None => format!("{} - Present", start),
+    }
+}
+
+// Joins a list of items like "a, b and c"
+pub fn join_with_and(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [item] => item.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+// A backend that a resume can be rendered with.
+//
+// Backends only implement the writing primitives, the section hooks have default
+// implementations so every backend lays out every section the same way.
+// `render_resume` walks the resume once, calling each section hook in order.
+pub trait ResumeRenderer {
+    // Primitives
+    fn write_section_header(&mut self, text: &str) -> Result<()>;
+    fn write_item_header(&mut self, text: &str) -> Result<()>;
+    fn write_extra_info(&mut self, text: &str) -> Result<()>;
+    fn write_normal(&mut self, text: &str) -> Result<()>;
+    fn write_bullets(&mut self, items: &[String]) -> Result<()>;
+
+    // Bullets that belong to a scored item, backends can override this to show the score
+    fn write_item_bullets<T>(
+        &mut self,
+        _item: &SortableResumeItem<T>,
+        bullets: &[String],
+    ) -> Result<()> {
+        self.write_bullets(bullets)
+    }
+
+    // Consumes the renderer and returns the rendered document
+    fn finish(self) -> Result<Vec<u8>>
+    where
+        Self: Sized;
+
+    // Section hooks
+    fn render_header(&mut self, resume: &Resume) -> Result<()> {
+        if let Some(name) = resume.get_name() {
+            self.write_section_header(name)?;
+        }
+
+        // Linked in | website | phone number
+        let mut contact = Vec::new();
+        if let Some(linked_in) = resume.get_linked_in() {
+            contact.push(linked_in.clone());
+        }
+        if let Some(website) = resume.get_website() {
+            contact.push(website.clone());
+        }
+        if let Some(phone_number) = resume.get_phone_number() {
+            contact.push(phone_number.to_string().trim().to_string());
+        }
+
+        if !contact.is_empty() {
+            self.write_extra_info(&contact.join(" | "))?;
+        }
+
+        Ok(())
+    }
+
+    fn render_skills(
+        &mut self,
+        skills: &[(String, Vec<SortableResumeItem<String>>)],
+    ) -> Result<()> {
+        if skills.is_empty() {
+            return Ok(());
+        }
+
+        self.write_section_header("Skills")?;
+
+        for (category, category_skills) in skills.iter() {
+            self.write_item_header(&capitalize(category))?;
+
+            let skill_names = category_skills
+                .iter()
+                .map(|skill| skill.sortable.clone())
+                .collect::<Vec<String>>();
+
+            self.write_normal(&skill_names.join(", "))?;
+        }
+
+        Ok(())
+    }
+
+    fn render_work_experience(
+        &mut self,
+        work_experience: &[SortableResumeItem<WorkExperience>],
+    ) -> Result<()> {
+        if work_experience.is_empty() {
+            return Ok(());
+        }
+
+        self.write_section_header("Work Experience")?;
+
+        for experience in work_experience.iter() {
+            let e = &experience.sortable;
+
+            // Job title and dates
+            self.write_item_header(&format!(
+                "{} {}",
+                e.get_job_title(),
+                format_date_range(e.get_job_start(), e.get_job_end())
+            ))?;
+
+            // Company and location
+            match e.get_job_location() {
+                Some(location) => {
+                    self.write_extra_info(&format!("{}, {}", e.get_company_name(), location))?
+                }
+                None => self.write_extra_info(e.get_company_name())?,
+            }
+
+            // Job description
+            self.write_item_bullets(experience, e.get_job_description())?;
+        }
+
+        Ok(())
+    }
+
+    fn render_education(&mut self, education: &[SortableResumeItem<Education>]) -> Result<()> {
+        if education.is_empty() {
+            return Ok(());
+        }
+
+        self.write_section_header("Education")?;
+
+        for edu in education.iter() {
+            let e = &edu.sortable;
+
+            // School and dates
+            self.write_item_header(&format!(
+                "{} {}",
+                e.get_school_name(),
+                format_date_range(e.get_education_start(), e.get_education_end())
+            ))?;
+
+            // Major(s) and location
+            self.write_extra_info(&format!(
+                "{}, {}",
+                join_with_and(e.get_major()),
+                e.get_location()
+            ))?;
+
+            // Minor(s)
+            if !e.get_minor().is_empty() {
+                self.write_extra_info(&format!("Minor in {}", join_with_and(e.get_minor())))?;
+            }
+
+            // Relevant coursework and GPA
+            let mut bullets = Vec::new();
+            if !e.get_coursework().is_empty() {
+                bullets.push(format!(
+                    "Relevant Coursework: {}",
+                    e.get_coursework().join(", ")
+                ));
+            }
+            bullets.push(format!("{} GPA", e.get_gpa()));
+
+            self.write_item_bullets(edu, &bullets)?;
+        }
+
+        Ok(())
+    }
+
+    fn render_projects(&mut self, projects: &[SortableResumeItem<Project>]) -> Result<()> {
+        if projects.is_empty() {
+            return Ok(());
+        }
+
+        self.write_section_header("Projects")?;
+
+        for project in projects.iter() {
+            let e = &project.sortable;
+
+            // Project title and dates
+            self.write_item_header(&format!(
+                "{} {}",
+                e.get_project_name(),
+                format_date_range(e.get_project_start(), e.get_project_end())
+            ))?;
+
+            // Project descriptions
+            self.write_item_bullets(project, e.get_project_description())?;
+        }
+
+        Ok(())
+    }
+
+    // Renders every section of the resume in order
+    fn render_resume(&mut self, resume: &Resume) -> Result<()> {
+        self.render_header(resume)?;
+        self.render_skills(resume.get_skills())?;
+        self.render_work_experience(resume.get_work_experience())?;
+        self.render_education(resume.get_education())?;
+        self.render_projects(resume.get_projects())?;
+
+        Ok(())
+    }
+}
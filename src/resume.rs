@@ -1,3 +1,8 @@
+use crate::breakdown::ScoreBreakdown;
+use crate::error::{Result, ResumeError};
+use crate::ranking::RankingStrategy;
+use crate::scorer::{sum_or_zero, Bm25, Scorer};
+use crate::synonyms::Synonyms;
 use crate::word_cloud::WordCloud;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, fmt::Display, fs, io::Read};
@@ -14,31 +19,41 @@ impl Date {
     pub fn new(year: i32, month: Option<u8>, day: Option<u8>) -> Self {
         Self { year, month, day }
     }
+
+    pub fn get_year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn get_month(&self) -> Option<u8> {
+        self.month
+    }
+
+    pub fn get_day(&self) -> Option<u8> {
+        self.day
+    }
 }
 
 impl Display for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} /", self.year)?;
-        if self.month.is_some() {
-            write!(f, " {} /", self.month.unwrap())?;
+        if let Some(month) = self.month {
+            write!(f, " {} /", month)?;
         }
-        if self.day.is_some() {
-            write!(f, " {}", self.day.unwrap())?;
+        if let Some(day) = self.day {
+            write!(f, " {}", day)?;
         }
 
         Ok(())
     }
 }
 
-
 #[derive(Serialize, Deserialize)]
 pub struct PhoneNumber {
     country_code: String,
     area_code: Option<String>,
-    phone_number: String
+    phone_number: String,
 }
 
-
 impl PhoneNumber {
     // Phone number must be formatted like "+1 (555) 2321 123 123
     // the country code must have a + in front and the area code must be in ()
@@ -55,7 +70,7 @@ impl PhoneNumber {
             } else if split.contains("(") && split.contains(")") {
                 ac = Some(split.to_string());
             } else {
-                pn.push_str(&split);
+                pn.push_str(split);
             }
         }
 
@@ -69,30 +84,24 @@ impl PhoneNumber {
     #[allow(unused)]
     pub fn from(phone_number: &PhoneNumber) -> Self {
         Self {
-            country_code: String::from(phone_number.country_code.clone()),
-            area_code: {
-                match &phone_number.area_code {
-                    Some(ac) => { Some(ac.to_string()) },
-                    None => None
-                }
-            },
-            phone_number: String::from(phone_number.phone_number.clone()),
+            country_code: phone_number.country_code.clone(),
+            area_code: { phone_number.area_code.as_ref().map(|ac| ac.to_string()) },
+            phone_number: phone_number.phone_number.clone(),
         }
     }
 }
 
 impl Display for PhoneNumber {
-   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-       write!(f, "{}", self.country_code)?;
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.country_code)?;
 
-       match &self.area_code {
-           Some(code) => { write!(f, " {}", code)? },
-           None => {}
-       }
+        if let Some(code) = &self.area_code {
+            write!(f, " {}", code)?;
+        }
 
-       writeln!(f, " {}", self.phone_number)?;
-       Ok(())
-   }
+        writeln!(f, " {}", self.phone_number)?;
+        Ok(())
+    }
 }
 
 //----------------------------------------------
@@ -102,31 +111,66 @@ pub struct Skills {
     //                     |    Category
     //                     V       V
     skill_tree: HashMap<String, Vec<String>>,
+    // Aliases that skills are renamed to, like "k8s" to "Kubernetes"
+    #[serde(skip, default = "Synonyms::builtin")]
+    synonyms: Synonyms,
+}
+
+impl Default for Skills {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Skills {
     pub fn new() -> Self {
         Self {
             skill_tree: HashMap::new(),
+            synonyms: Synonyms::builtin(),
         }
     }
 
+    // Skills are stored under their canonical name, a skill that is already
+    // in its category under any of its names is not added again
     pub fn add_skill(&mut self, skill_name: String, category: Option<String>) {
         let mut category_str = String::from("default");
-        if category.is_some() {
-            category_str = category.unwrap().to_ascii_lowercase();
+        if let Some(category) = category {
+            category_str = category.to_ascii_lowercase();
         }
 
-        // Check if the skill is already in the tree and add it if it is not
-        if self.skill_tree.contains_key(&category_str) {
-            self.skill_tree
-                .get_mut(&category_str)
-                .unwrap()
-                .push(skill_name);
-        } else {
-            self.skill_tree.insert(category_str, vec![skill_name]);
+        // Add the skill to its category, creating the category if it is not in the tree
+        let skill_name = self.synonyms.normalize(&skill_name);
+        push_skill(self.skill_tree.entry(category_str).or_default(), skill_name);
+    }
+
+    pub fn get_synonyms(&self) -> &Synonyms {
+        &self.synonyms
+    }
+
+    // Renames the skills already in the tree with the new synonyms too
+    pub fn set_synonyms(&mut self, synonyms: Synonyms) {
+        self.synonyms = synonyms;
+
+        for skills in self.skill_tree.values_mut() {
+            for skill in std::mem::take(skills) {
+                push_skill(skills, self.synonyms.normalize(&skill));
+            }
         }
     }
+
+    pub fn get_skill_tree(&self) -> &HashMap<String, Vec<String>> {
+        &self.skill_tree
+    }
+}
+
+// Adds the skill unless the list already has it in any case
+fn push_skill(skills: &mut Vec<String>, skill_name: String) {
+    if !skills
+        .iter()
+        .any(|skill| skill.eq_ignore_ascii_case(&skill_name))
+    {
+        skills.push(skill_name);
+    }
 }
 
 impl Display for Skills {
@@ -185,8 +229,8 @@ impl WorkExperience {
             company_name: String::from(&work_experience.company_name),
             job_location: work_experience.job_location.clone(),
             job_description: work_experience.job_description.clone(),
-            job_start: work_experience.job_start.clone(),
-            job_end: work_experience.job_end.clone(),
+            job_start: work_experience.job_start,
+            job_end: work_experience.job_end,
         }
     }
 
@@ -207,6 +251,10 @@ impl WorkExperience {
         &self.job_description
     }
 
+    pub fn remove_job_description(&mut self, index: usize) -> String {
+        self.job_description.remove(index)
+    }
+
     pub fn get_job_start(&self) -> &Date {
         &self.job_start
     }
@@ -233,8 +281,8 @@ impl Display for WorkExperience {
 
         write!(f, "        Job Start: {}", self.job_start)?;
 
-        if self.job_end.is_some() {
-            write!(f, "        Job End: {}", &self.job_end.clone().unwrap())?;
+        if let Some(job_end) = self.job_end {
+            write!(f, "        Job End: {}", job_end)?;
         } else {
             writeln!(f, "        Job End: N/A")?;
         }
@@ -264,6 +312,7 @@ pub struct Education {
 }
 
 impl Education {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         school_name: String,
         major: Vec<String>,
@@ -295,8 +344,8 @@ impl Education {
             minor: education.minor.clone(),
             coursework: education.coursework.clone(),
             gpa: education.gpa,
-            education_start: education.education_start.clone(),
-            education_end: education.education_end.clone(),
+            education_start: education.education_start,
+            education_end: education.education_end,
         }
     }
 
@@ -322,6 +371,10 @@ impl Education {
         &self.coursework
     }
 
+    pub fn remove_coursework(&mut self, index: usize) -> String {
+        self.coursework.remove(index)
+    }
+
     pub fn get_gpa(&self) -> f32 {
         self.gpa
     }
@@ -362,8 +415,8 @@ impl Display for Education {
         writeln!(f, "    GPA: {}", self.gpa)?;
         write!(f, "    Start Date: {}", self.education_start)?;
 
-        if self.education_end.is_some() {
-            write!(f, "    End Date: {}", self.education_end.unwrap())?;
+        if let Some(education_end) = self.education_end {
+            write!(f, "    End Date: {}", education_end)?;
         } else {
             writeln!(f, "    End Date: N/A")?;
         }
@@ -403,17 +456,21 @@ impl Project {
         Self {
             project_name: project.project_name.clone(),
             project_description: project.project_description.clone(),
-            project_start: project.project_start.clone(),
-            project_end: project.project_end.clone(),
+            project_start: project.project_start,
+            project_end: project.project_end,
         }
     }
 
     pub fn get_project_name(&self) -> &String {
-        &self.project_name 
+        &self.project_name
     }
 
     pub fn get_project_description(&self) -> &Vec<String> {
-        &self.project_description 
+        &self.project_description
+    }
+
+    pub fn remove_project_description(&mut self, index: usize) -> String {
+        self.project_description.remove(index)
     }
 
     pub fn get_project_start(&self) -> &Date {
@@ -435,8 +492,8 @@ impl Display for Project {
 
         writeln!(f, "    Start Date: {}", self.project_start)?;
 
-        if self.project_end.is_some() {
-            writeln!(f, "    End Date: {}", self.project_end.unwrap())?;
+        if let Some(project_end) = self.project_end {
+            writeln!(f, "    End Date: {}", project_end)?;
         } else {
             writeln!(f, "    End Date: N/A")?;
         }
@@ -456,7 +513,13 @@ pub struct CV {
     skills: Skills,
     work_experience: Vec<WorkExperience>,
     education: Vec<Education>,
-    projects: Vec<Project>
+    projects: Vec<Project>,
+}
+
+impl Default for CV {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl CV {
@@ -479,7 +542,7 @@ impl CV {
     }
 
     pub fn set_linked_in(&mut self, linked_in: String) {
-        self.linked_in = Some(linked_in); 
+        self.linked_in = Some(linked_in);
     }
 
     pub fn set_website(&mut self, website: String) {
@@ -495,6 +558,10 @@ impl CV {
         self.skills.add_skill(skill_name, category);
     }
 
+    pub fn set_synonyms(&mut self, synonyms: Synonyms) {
+        self.skills.set_synonyms(synonyms);
+    }
+
     pub fn add_work_experience(
         &mut self,
         job_title: String,
@@ -514,6 +581,7 @@ impl CV {
         ));
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn add_education(
         &mut self,
         school_name: String,
@@ -552,11 +620,30 @@ impl CV {
         ));
     }
 
+    // CV getters
+    pub fn get_skills(&self) -> &Skills {
+        &self.skills
+    }
+
+    pub fn get_work_experience(&self) -> &Vec<WorkExperience> {
+        &self.work_experience
+    }
+
+    pub fn get_education(&self) -> &Vec<Education> {
+        &self.education
+    }
+
+    pub fn get_projects(&self) -> &Vec<Project> {
+        &self.projects
+    }
+
     // Functions to sort and create resume
+    // Every list is sorted with the most relevant item first
     #[allow(unused)]
     fn create_sorted_skill_list(
         &self,
         word_cloud: &WordCloud,
+        scorer: &impl Scorer,
     ) -> Vec<(String, Vec<SortableResumeItem<String>>)> {
         let mut output: Vec<(String, Vec<SortableResumeItem<String>>)> = Vec::new();
 
@@ -564,126 +651,198 @@ impl CV {
             let mut category_skills: Vec<SortableResumeItem<String>> = Vec::new();
 
             for s in category.1 {
-                category_skills.push(SortableResumeItem::new(
-                    s.clone(),
-                    word_cloud.get_word_score(s.clone()).get_word_weight(),
-                ));
+                let mut skill = SortableResumeItem::new(s.clone(), scorer.score(word_cloud, s));
+                skill.set_breakdown(ScoreBreakdown::new(word_cloud, scorer, [s], []));
+                category_skills.push(skill);
             }
 
-            category_skills.sort_by_key(|skill| skill.point_value);
+            sort_descending(&mut category_skills);
 
             output.push((category.0.clone(), category_skills));
         }
 
+        // Put the most relevant category first, categories that tie stay in name order
+        let category_score = |skills: &Vec<SortableResumeItem<String>>| {
+            sum_or_zero(skills.iter().map(|skill| skill.point_value))
+        };
+        output.sort_by(|a, b| a.0.cmp(&b.0));
+        output.sort_by(|a, b| category_score(&b.1).total_cmp(&category_score(&a.1)));
+
         output
     }
 
     #[allow(unused)]
     fn create_sorted_work_experience_list(
-        &mut self,
+        &self,
         word_cloud: &WordCloud,
+        scorer: &impl Scorer,
     ) -> Vec<SortableResumeItem<WorkExperience>> {
         let mut output = Vec::new();
 
-        for experience in self.work_experience.iter_mut() {
-            // sort descriptions
-            experience.job_description.sort_by_key(|description| {
-                word_cloud
-                    .get_word_score(description.clone())
-                    .get_word_weight()
-            });
-
-            // Get total word weight
-            let mut word_weight = 0;
-            experience.job_description.iter().for_each(|description| {
-                word_weight += word_cloud
-                    .get_word_score(description.clone())
-                    .get_word_weight()
+        for experience in self.work_experience.iter() {
+            // sort descriptions in a copy so the CV keeps its order
+            let mut experience = WorkExperience::from(experience);
+            sort_strings_descending(&mut experience.job_description, |description| {
+                scorer.score(word_cloud, description)
             });
-            word_weight += word_cloud
-                .get_word_score(experience.job_title.clone())
-                .get_word_weight();
 
-            output.push(SortableResumeItem::new(
-                WorkExperience::from(experience),
-                word_weight,
-            ));
+            // Score the title and descriptions together
+            let point_value = scorer.score_all(
+                word_cloud,
+                std::iter::once(&experience.job_title).chain(experience.job_description.iter()),
+            );
+
+            let breakdown = ScoreBreakdown::new(
+                word_cloud,
+                scorer,
+                [&experience.job_title],
+                experience.job_description.iter(),
+            );
+
+            let mut item = SortableResumeItem::new(experience, point_value);
+            item.set_breakdown(breakdown);
+            output.push(item);
         }
-        output.sort_by_key(|experience| experience.point_value);
+        sort_descending(&mut output);
 
         output
     }
 
     #[allow(unused)]
     fn create_sorted_education_list(
-        &mut self,
+        &self,
         word_cloud: &WordCloud,
+        scorer: &impl Scorer,
     ) -> Vec<SortableResumeItem<Education>> {
         let mut output = Vec::new();
 
-        for education in self.education.iter_mut() {
-            // sort relevate course
-            education
-                .coursework
-                .sort_by_key(|course| word_cloud.get_word_score(course.clone()).get_word_weight());
-
-            // Get total word weight
-            let mut word_weight = 0;
-            education.coursework.iter().for_each(|course| {
-                word_weight += word_cloud.get_word_score(course.clone()).get_word_weight()
-            });
-            education.major.iter().for_each(|major| {
-                word_weight += word_cloud.get_word_score(major.clone()).get_word_weight()
-            });
-            education.minor.iter().for_each(|minor| {
-                word_weight += word_cloud.get_word_score(minor.clone()).get_word_weight()
+        for education in self.education.iter() {
+            // sort relevate course in a copy so the CV keeps its order
+            let mut education = Education::from(education);
+            sort_strings_descending(&mut education.coursework, |course| {
+                scorer.score(word_cloud, course)
             });
 
-            output.push(SortableResumeItem::new(
-                Education::from(education),
-                word_weight,
-            ));
+            // Score the coursework, majors and minors together
+            let point_value = scorer.score_all(
+                word_cloud,
+                education
+                    .coursework
+                    .iter()
+                    .chain(education.major.iter())
+                    .chain(education.minor.iter()),
+            );
+
+            // Majors and minors are the title, coursework the description
+            let breakdown = ScoreBreakdown::new(
+                word_cloud,
+                scorer,
+                education.major.iter().chain(education.minor.iter()),
+                education.coursework.iter(),
+            );
+
+            let mut item = SortableResumeItem::new(education, point_value);
+            item.set_breakdown(breakdown);
+            output.push(item);
         }
-        output.sort_by_key(|education| education.point_value);
+        sort_descending(&mut output);
 
         output
     }
 
     #[allow(unused)]
     fn create_sorted_project_list(
-        &mut self,
+        &self,
         word_cloud: &WordCloud,
+        scorer: &impl Scorer,
     ) -> Vec<SortableResumeItem<Project>> {
         let mut output = Vec::new();
 
-        for project in self.projects.iter_mut() {
-            // sort project descriptions
-            project
-                .project_description
-                .sort_by_key(|description| word_cloud.get_word_score(description.clone()).get_word_weight());
-
-            // get total word weight
-            let mut word_weight = 0;
-            project.project_description.iter().for_each(|description| {
-                word_weight += word_cloud.get_word_score(description.clone()).get_word_weight();
+        for project in self.projects.iter() {
+            // sort project descriptions in a copy so the CV keeps its order
+            let mut project = Project::from(project);
+            sort_strings_descending(&mut project.project_description, |description| {
+                scorer.score(word_cloud, description)
             });
 
+            // Score the descriptions together
+            let point_value = scorer.score_all(word_cloud, project.project_description.iter());
+
+            // The project name is not scored so it has no title contribution
+            let breakdown =
+                ScoreBreakdown::new(word_cloud, scorer, [], project.project_description.iter());
 
-            output.push(SortableResumeItem::new(
-                Project::from(project),
-                word_weight
-            ));
+            let mut item = SortableResumeItem::new(project, point_value);
+            item.set_breakdown(breakdown);
+            output.push(item);
         }
-        output.sort_by_key(|project| project.point_value);
+        sort_descending(&mut output);
 
         output
     }
 
-    // Creates a resume with vectors sorted by word cloud score ratio
+    // Creates a resume with vectors sorted by the default ranking strategy
     #[allow(unused)]
-    pub fn generate_resume(&mut self, word_cloud: &WordCloud) -> Resume {
+    pub fn generate_resume(&self, word_cloud: &WordCloud) -> Resume {
+        self.generate_resume_with_strategy(word_cloud, RankingStrategy::default())
+    }
+
+    // Creates a resume with vectors sorted by how the strategy scores them against the word cloud.
+    // BM25 uses the average length of the items in this CV
+    pub fn generate_resume_with_strategy(
+        &self,
+        word_cloud: &WordCloud,
+        strategy: RankingStrategy,
+    ) -> Resume {
+        match strategy {
+            RankingStrategy::Bm25 => {
+                self.generate_resume_with_scorer(word_cloud, &self.create_bm25(word_cloud))
+            }
+            strategy => self.generate_resume_with_scorer(word_cloud, &strategy),
+        }
+    }
+
+    // BM25 with the average length of the work experience, education and projects
+    // of this CV, the items whose scores are compared when ranking
+    pub fn create_bm25(&self, word_cloud: &WordCloud) -> Bm25 {
+        Bm25::with_average_length_of(word_cloud, self.get_item_texts().iter())
+    }
+
+    // The text of every work experience, education and project, joined the same
+    // way they are scored
+    fn get_item_texts(&self) -> Vec<String> {
+        let experience = self.work_experience.iter().map(|e| {
+            std::iter::once(&e.job_title)
+                .chain(e.job_description.iter())
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+        let education = self.education.iter().map(|e| {
+            e.coursework
+                .iter()
+                .chain(e.major.iter())
+                .chain(e.minor.iter())
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+        let projects = self.projects.iter().map(|p| p.project_description.clone());
+
+        experience
+            .chain(education)
+            .chain(projects)
+            .map(|texts| texts.join("\n"))
+            .collect()
+    }
+
+    // Creates a resume with vectors sorted by how the scorer scores them against the word cloud.
+    // The CV is left as it is so it can be tailored to any number of job descriptions
+    pub fn generate_resume_with_scorer(
+        &self,
+        word_cloud: &WordCloud,
+        scorer: &impl Scorer,
+    ) -> Resume {
         let mut resume = Resume::new();
-        
+
         // Add name to resume
         resume.name = self.name.clone();
         // Add linkedin to resume
@@ -691,41 +850,53 @@ impl CV {
         // Add website to resume
         resume.website = self.website.clone();
         // Add phone number to resume
-        resume.phone_number = {
-            match &self.phone_number {
-                Some(phone_number) => {
-                    Some(PhoneNumber::from(phone_number))
-                },
-                None => None
-            }
-        };
+        resume.phone_number = self.phone_number.as_ref().map(PhoneNumber::from);
         // Add Skills to resume
-        resume.skills = self.create_sorted_skill_list(&word_cloud);
+        resume.skills = self.create_sorted_skill_list(word_cloud, scorer);
         // Add Work experince to resume
-        resume.work_experience = self.create_sorted_work_experience_list(&word_cloud);
+        resume.work_experience = self.create_sorted_work_experience_list(word_cloud, scorer);
         // Add Education to resume
-        resume.education = self.create_sorted_education_list(&word_cloud);
+        resume.education = self.create_sorted_education_list(word_cloud, scorer);
         // Add Projects to resume
-        resume.projects = self.create_sorted_project_list(&word_cloud);
+        resume.projects = self.create_sorted_project_list(word_cloud, scorer);
 
         resume
     }
 
     // Saving and loading file
     #[allow(unused)]
-    pub fn save_to_file(&self, filename: String) {
-        let saved_file = serde_json::to_string(self).unwrap();
-
-        fs::write(filename, saved_file).expect("Unable to write to file");
+    pub fn save_to_file(&self, filename: String) -> Result<()> {
+        let mut saved_file = Vec::new();
+        serde_path_to_error::serialize(self, &mut serde_json::Serializer::new(&mut saved_file))
+            .map_err(|error| ResumeError::Parse {
+                filename: filename.clone(),
+                json_path: error.path().to_string(),
+                source: error.into_inner(),
+            })?;
+
+        fs::write(&filename, saved_file).map_err(|error| ResumeError::io(filename, error))
     }
 
     #[allow(unused)]
-    pub fn load_from_file(&mut self, filename: String) {
+    pub fn load_from_file(&mut self, filename: String) -> Result<()> {
         let mut contents = String::new();
-        let _ = fs::File::open(filename)
-            .unwrap()
-            .read_to_string(&mut contents);
-        *self = serde_json::from_str(&contents.as_str()).unwrap();
+        fs::File::open(&filename)
+            .and_then(|mut file| file.read_to_string(&mut contents))
+            .map_err(|error| ResumeError::io(filename.clone(), error))?;
+
+        let deserializer = &mut serde_json::Deserializer::from_str(contents.as_str());
+        *self =
+            serde_path_to_error::deserialize(deserializer).map_err(|error| ResumeError::Parse {
+                filename,
+                json_path: error.path().to_string(),
+                source: error.into_inner(),
+            })?;
+
+        // Skills in the file are renamed the same way as added ones
+        let synonyms = self.skills.get_synonyms().clone();
+        self.skills.set_synonyms(synonyms);
+
+        Ok(())
     }
 }
 
@@ -756,17 +927,47 @@ impl Display for CV {
 #[allow(unused)]
 pub struct SortableResumeItem<T> {
     pub sortable: T,
-    point_value: i32,
+    point_value: f32,
+    breakdown: ScoreBreakdown,
 }
 
 impl<T> SortableResumeItem<T> {
     #[allow(unused)]
-    pub fn new(sortable: T, point_value: i32) -> Self {
+    pub fn new(sortable: T, point_value: f32) -> Self {
         Self {
             sortable,
             point_value,
+            breakdown: ScoreBreakdown::default(),
         }
     }
+
+    pub fn get_point_value(&self) -> f32 {
+        self.point_value
+    }
+
+    pub fn get_breakdown(&self) -> &ScoreBreakdown {
+        &self.breakdown
+    }
+
+    pub fn set_breakdown(&mut self, breakdown: ScoreBreakdown) {
+        self.breakdown = breakdown;
+    }
+}
+
+// Sorts the items with the highest point value first, ties keep their order
+fn sort_descending<T>(items: &mut [SortableResumeItem<T>]) {
+    items.sort_by(|a, b| b.point_value.total_cmp(&a.point_value));
+}
+
+// Sorts the strings with the highest score first, ties keep their order
+fn sort_strings_descending(strings: &mut Vec<String>, score: impl Fn(&String) -> f32) {
+    let mut scored = strings
+        .drain(..)
+        .map(|string| (score(&string), string))
+        .collect::<Vec<(f32, String)>>();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    strings.extend(scored.into_iter().map(|(_, string)| string));
 }
 
 pub struct Resume {
@@ -780,6 +981,12 @@ pub struct Resume {
     projects: Vec<SortableResumeItem<Project>>,
 }
 
+impl Default for Resume {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Resume {
     #[allow(unused)]
     pub fn new() -> Self {
@@ -811,6 +1018,37 @@ impl Resume {
         &self.projects
     }
 
+    // Sum of the point value of every skill, work experience, education and project
+    pub fn get_total_score(&self) -> f32 {
+        let skills = self
+            .skills
+            .iter()
+            .flat_map(|(_, skills)| skills.iter())
+            .map(|skill| skill.point_value);
+        let work_experience = self.work_experience.iter().map(|item| item.point_value);
+        let education = self.education.iter().map(|item| item.point_value);
+        let projects = self.projects.iter().map(|item| item.point_value);
+
+        sum_or_zero(
+            skills
+                .chain(work_experience)
+                .chain(education)
+                .chain(projects),
+        )
+    }
+
+    pub fn get_work_experience_mut(&mut self) -> &mut Vec<SortableResumeItem<WorkExperience>> {
+        &mut self.work_experience
+    }
+
+    pub fn get_education_mut(&mut self) -> &mut Vec<SortableResumeItem<Education>> {
+        &mut self.education
+    }
+
+    pub fn get_projects_mut(&mut self) -> &mut Vec<SortableResumeItem<Project>> {
+        &mut self.projects
+    }
+
     pub fn get_name(&self) -> &Option<String> {
         &self.name
     }
@@ -818,11 +1056,11 @@ impl Resume {
     pub fn get_linked_in(&self) -> &Option<String> {
         &self.linked_in
     }
-    
+
     pub fn get_website(&self) -> &Option<String> {
         &self.website
     }
-    
+
     pub fn get_phone_number(&self) -> &Option<PhoneNumber> {
         &self.phone_number
     }
@@ -0,0 +1,136 @@
+use crate::word_cloud::WordCloud;
+use std::collections::HashMap;
+
+// Scores resume text against the word cloud of a job description,
+// a higher score means the text is more relevant to the job
+pub trait Scorer {
+    fn score(&self, word_cloud: &WordCloud, text: &str) -> f32;
+
+    // Scores several pieces of text as one, used for items made up of a title and bullets
+    fn score_all<'a>(
+        &self,
+        word_cloud: &WordCloud,
+        texts: impl IntoIterator<Item = &'a String>,
+    ) -> f32
+    where
+        Self: Sized,
+    {
+        let joined = texts
+            .into_iter()
+            .map(|text| text.as_str())
+            .collect::<Vec<&str>>()
+            .join("\n");
+
+        self.score(word_cloud, &joined)
+    }
+}
+
+// Adds up scores or weights. Summing floats with sum() gives -0 when there is
+// nothing to add, which prints as "-0", so the sum starts from 0 instead
+pub(crate) fn sum_or_zero(values: impl IntoIterator<Item = f32>) -> f32 {
+    values.into_iter().fold(0.0, |total, value| total + value)
+}
+
+// Usual BM25 parameters
+const DEFAULT_K1: f32 = 1.2;
+const DEFAULT_B: f32 = 0.75;
+// Number of words in a typical resume bullet
+const DEFAULT_AVERAGE_LENGTH: f32 = 12.0;
+
+// Okapi BM25 with the word cloud as the query. Every matching term counts its
+// word cloud weight once, scaled by how often it repeats in the text with
+// diminishing returns, so repeating a keyword does not keep raising the score.
+// Text longer than the average length is scored down
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bm25 {
+    // How quickly repeated terms stop adding to the score
+    k1: f32,
+    // How much the length of the text matters, 0 ignores it and 1 fully normalizes it
+    b: f32,
+    // Average number of terms in the text being scored
+    average_length: f32,
+}
+
+impl Default for Bm25 {
+    fn default() -> Self {
+        Self::new(DEFAULT_K1, DEFAULT_B, DEFAULT_AVERAGE_LENGTH)
+    }
+}
+
+impl Bm25 {
+    pub fn new(k1: f32, b: f32, average_length: f32) -> Self {
+        Self {
+            k1,
+            b,
+            average_length,
+        }
+    }
+
+    // Uses the average length of the given texts, like all the bullets in a CV
+    pub fn with_average_length_of<'a>(
+        word_cloud: &WordCloud,
+        texts: impl IntoIterator<Item = &'a String>,
+    ) -> Self {
+        let lengths: Vec<usize> = texts
+            .into_iter()
+            .map(|text| word_cloud.get_term_weights(text.clone()).len())
+            .collect();
+
+        let mut ret = Self::default();
+        if !lengths.is_empty() {
+            ret.average_length =
+                (lengths.iter().sum::<usize>() as f32 / lengths.len() as f32).max(1.0);
+        }
+
+        ret
+    }
+}
+
+impl Scorer for Bm25 {
+    fn score(&self, word_cloud: &WordCloud, text: &str) -> f32 {
+        let terms = word_cloud.get_term_weights(text.to_string());
+        let length_norm = 1.0 - self.b + self.b * terms.len() as f32 / self.average_length;
+
+        //                   Term
+        //                    |   (Weight, Frequency)
+        //                    V      V
+        let mut frequencies: HashMap<String, (f32, f32)> = HashMap::new();
+        for (term, weight) in terms {
+            frequencies.entry(term).or_insert((weight, 0.0)).1 += 1.0;
+        }
+
+        sum_or_zero(frequencies.values().map(|(weight, frequency)| {
+            weight * frequency * (self.k1 + 1.0) / (frequency + self.k1 * length_norm)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn average_length_comes_from_the_texts() {
+        let word_cloud = WordCloud::create_from_text("rust services".to_string());
+        let texts = ["built rust services".to_string(), "wrote rust".to_string()];
+
+        assert_eq!(
+            Bm25::with_average_length_of(&word_cloud, texts.iter()).average_length,
+            2.5
+        );
+        assert_eq!(
+            Bm25::with_average_length_of(&word_cloud, []).average_length,
+            DEFAULT_AVERAGE_LENGTH
+        );
+    }
+
+    #[test]
+    fn text_of_average_length_is_not_scored_down() {
+        let word_cloud = WordCloud::create_from_text("rust".to_string());
+        let text = "rust go java lua";
+        let plain = Bm25::new(DEFAULT_K1, DEFAULT_B, 4.0).score(&word_cloud, text);
+        let without_length = Bm25::new(DEFAULT_K1, 0.0, 4.0).score(&word_cloud, text);
+
+        assert_eq!(plain, without_length);
+    }
+}
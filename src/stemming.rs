@@ -0,0 +1,71 @@
+use rust_stemmers::{Algorithm, Stemmer};
+use std::str::FromStr;
+
+// Languages words can be stemmed in, so "developed", "developing" and
+// "develop" all count as the same word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StemmingLanguage {
+    Danish,
+    Dutch,
+    English,
+    Finnish,
+    French,
+    German,
+    Hungarian,
+    Italian,
+    Norwegian,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+    Swedish,
+}
+
+impl StemmingLanguage {
+    pub fn create_stemmer(&self) -> Stemmer {
+        Stemmer::create(self.algorithm())
+    }
+
+    fn algorithm(&self) -> Algorithm {
+        match self {
+            StemmingLanguage::Danish => Algorithm::Danish,
+            StemmingLanguage::Dutch => Algorithm::Dutch,
+            StemmingLanguage::English => Algorithm::English,
+            StemmingLanguage::Finnish => Algorithm::Finnish,
+            StemmingLanguage::French => Algorithm::French,
+            StemmingLanguage::German => Algorithm::German,
+            StemmingLanguage::Hungarian => Algorithm::Hungarian,
+            StemmingLanguage::Italian => Algorithm::Italian,
+            StemmingLanguage::Norwegian => Algorithm::Norwegian,
+            StemmingLanguage::Portuguese => Algorithm::Portuguese,
+            StemmingLanguage::Romanian => Algorithm::Romanian,
+            StemmingLanguage::Russian => Algorithm::Russian,
+            StemmingLanguage::Spanish => Algorithm::Spanish,
+            StemmingLanguage::Swedish => Algorithm::Swedish,
+        }
+    }
+}
+
+impl FromStr for StemmingLanguage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "danish" | "da" => Ok(StemmingLanguage::Danish),
+            "dutch" | "nl" => Ok(StemmingLanguage::Dutch),
+            "english" | "en" => Ok(StemmingLanguage::English),
+            "finnish" | "fi" => Ok(StemmingLanguage::Finnish),
+            "french" | "fr" => Ok(StemmingLanguage::French),
+            "german" | "de" => Ok(StemmingLanguage::German),
+            "hungarian" | "hu" => Ok(StemmingLanguage::Hungarian),
+            "italian" | "it" => Ok(StemmingLanguage::Italian),
+            "norwegian" | "no" => Ok(StemmingLanguage::Norwegian),
+            "portuguese" | "pt" => Ok(StemmingLanguage::Portuguese),
+            "romanian" | "ro" => Ok(StemmingLanguage::Romanian),
+            "russian" | "ru" => Ok(StemmingLanguage::Russian),
+            "spanish" | "es" => Ok(StemmingLanguage::Spanish),
+            "swedish" | "sv" => Ok(StemmingLanguage::Swedish),
+            other => Err(format!("unknown stemming language: {}", other)),
+        }
+    }
+}
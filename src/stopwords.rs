@@ -0,0 +1,241 @@
+use crate::error::{Result, ResumeError};
+use std::{collections::HashSet, fs};
+
+// Common English words that say nothing about what a job needs
+const ENGLISH_STOPWORDS: &[&str] = &[
+    "a",
+    "about",
+    "above",
+    "after",
+    "again",
+    "against",
+    "all",
+    "also",
+    "am",
+    "an",
+    "and",
+    "any",
+    "are",
+    "as",
+    "at",
+    "be",
+    "because",
+    "been",
+    "before",
+    "being",
+    "below",
+    "between",
+    "both",
+    "but",
+    "by",
+    "can",
+    "could",
+    "did",
+    "do",
+    "does",
+    "doing",
+    "down",
+    "during",
+    "each",
+    "etc",
+    "every",
+    "few",
+    "for",
+    "from",
+    "further",
+    "had",
+    "has",
+    "have",
+    "having",
+    "he",
+    "her",
+    "here",
+    "hers",
+    "herself",
+    "him",
+    "himself",
+    "his",
+    "how",
+    "i",
+    "i'm",
+    "i've",
+    "if",
+    "in",
+    "including",
+    "into",
+    "is",
+    "it",
+    "it's",
+    "its",
+    "itself",
+    "just",
+    "let's",
+    "like",
+    "may",
+    "me",
+    "might",
+    "more",
+    "most",
+    "must",
+    "my",
+    "myself",
+    "no",
+    "nor",
+    "not",
+    "now",
+    "of",
+    "off",
+    "on",
+    "once",
+    "one",
+    "only",
+    "or",
+    "other",
+    "our",
+    "ours",
+    "ourselves",
+    "out",
+    "over",
+    "own",
+    "per",
+    "same",
+    "she",
+    "should",
+    "so",
+    "some",
+    "such",
+    "than",
+    "that",
+    "that's",
+    "the",
+    "their",
+    "theirs",
+    "them",
+    "themselves",
+    "then",
+    "there",
+    "there's",
+    "these",
+    "they",
+    "they're",
+    "this",
+    "those",
+    "through",
+    "to",
+    "too",
+    "under",
+    "until",
+    "up",
+    "us",
+    "very",
+    "was",
+    "we",
+    "we'll",
+    "we're",
+    "we've",
+    "were",
+    "what",
+    "what's",
+    "when",
+    "where",
+    "which",
+    "while",
+    "who",
+    "whom",
+    "why",
+    "will",
+    "with",
+    "within",
+    "would",
+    "you",
+    "you'll",
+    "you're",
+    "you've",
+    "your",
+    "yours",
+    "yourself",
+    "yourselves",
+];
+
+// A set of words to leave out of word clouds and scoring
+#[derive(Clone, Default)]
+pub struct Stopwords {
+    words: HashSet<String>,
+}
+
+impl Stopwords {
+    // No stopwords, every word counts
+    pub fn new() -> Self {
+        Self {
+            words: HashSet::new(),
+        }
+    }
+
+    // The built in English stopword list
+    pub fn english() -> Self {
+        let mut stopwords = Self::new();
+        stopwords.extend(ENGLISH_STOPWORDS.iter().map(|word| word.to_string()));
+        stopwords
+    }
+
+    // Reads stopwords from a file with words separated by whitespace,
+    // a # at the start of a line or after whitespace starts a comment
+    pub fn load_from_file(filename: String) -> Result<Self> {
+        let contents =
+            fs::read_to_string(&filename).map_err(|error| ResumeError::io(filename, error))?;
+
+        let mut stopwords = Self::new();
+        for line in contents.lines() {
+            let line = strip_comment(line);
+            stopwords.extend(line.split_whitespace().map(|word| word.to_lowercase()));
+        }
+
+        Ok(stopwords)
+    }
+
+    pub fn extend(&mut self, words: impl IntoIterator<Item = String>) {
+        self.words.extend(words);
+    }
+
+    pub fn into_words(self) -> impl Iterator<Item = String> {
+        self.words.into_iter()
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(word)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+// Drops the comment from a line of a word list. A # only starts a comment at the
+// start of the line or after whitespace, so words like "c#" can still be listed
+pub(crate) fn strip_comment(line: &str) -> &str {
+    let mut previous = ' ';
+    for (i, c) in line.char_indices() {
+        if c == '#' && previous.is_whitespace() {
+            return &line[..i];
+        }
+        previous = c;
+    }
+
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_comment_keeps_hashes_inside_words() {
+        assert_eq!(strip_comment("# a comment"), "");
+        assert_eq!(strip_comment("c# f#  # languages"), "c# f#  ");
+        assert_eq!(strip_comment("C#: csharp"), "C#: csharp");
+    }
+}
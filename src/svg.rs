@@ -0,0 +1,287 @@
+use crate::error::{Result, ResumeError};
+use crate::gap::GapReport;
+use crate::resume::CV;
+use crate::word_cloud::WordCloud;
+use std::fs;
+
+// Only the heaviest keywords are drawn
+const MAX_WORDS: usize = 75;
+const MIN_FONT_SIZE: f32 = 12.0;
+const MAX_FONT_SIZE: f32 = 48.0;
+// Rough width of a character and height of a line relative to the font size,
+// used to keep words from overlapping without loading a font
+const CHARACTER_WIDTH: f32 = 0.6;
+const LINE_HEIGHT: f32 = 1.1;
+const PADDING: f32 = 10.0;
+// Words are placed along a spiral out from the center
+const SPIRAL_STEP: f32 = 0.1;
+const SPIRAL_SPACING: f32 = 4.0;
+const MAX_SPIRAL_STEPS: usize = 20000;
+
+const COVERED_COLOR: &str = "#2e7d32";
+const MISSING_COLOR: &str = "#c62828";
+const NEUTRAL_COLOR: &str = "#1565c0";
+
+// A word placed in the cloud, x and y are the center of its box
+struct PlacedWord {
+    text: String,
+    font_size: f32,
+    color: &'static str,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+impl PlacedWord {
+    fn overlaps(&self, other: &PlacedWord) -> bool {
+        (self.x - other.x).abs() * 2.0 < self.width + other.width
+            && (self.y - other.y).abs() * 2.0 < self.height + other.height
+    }
+}
+
+// Escapes the characters that are not allowed in svg text
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+// Renders the heaviest keywords of the word cloud as a standalone svg image, font
+// size grows with weight. With a CV, keywords it covers and keywords it is missing
+// get different colors and a legend is added
+pub fn render_word_cloud_svg(word_cloud: &WordCloud, cv: Option<&CV>) -> String {
+    let mut keywords: Vec<(String, f32, &'static str)> = match cv {
+        Some(cv) => {
+            let report = GapReport::new(cv, word_cloud);
+            let covered = report.get_covered().iter();
+            let covered = covered.chain(report.get_covered_phrases()).map(|keyword| {
+                (
+                    keyword.get_keyword().clone(),
+                    keyword.get_weight(),
+                    COVERED_COLOR,
+                )
+            });
+            let missing = report
+                .get_missing()
+                .iter()
+                .chain(report.get_missing_phrases())
+                .map(|(keyword, weight)| (keyword.clone(), *weight, MISSING_COLOR));
+
+            let mut keywords: Vec<_> = covered.chain(missing).collect();
+            keywords.sort_by(|a, b| a.0.cmp(&b.0));
+            keywords.sort_by(|a, b| b.1.total_cmp(&a.1));
+            keywords
+        }
+        None => word_cloud
+            .get_keywords()
+            .into_iter()
+            .map(|(keyword, weight)| (word_cloud.get_display_form(&keyword), weight, NEUTRAL_COLOR))
+            .collect(),
+    };
+
+    keywords.truncate(MAX_WORDS);
+
+    let max_weight = keywords.first().map_or(1.0, |(_, weight, _)| *weight);
+    let min_weight = keywords.last().map_or(0.0, |(_, weight, _)| *weight);
+
+    let mut placed: Vec<PlacedWord> = Vec::new();
+    for (text, weight, color) in keywords {
+        let scale = if max_weight > min_weight {
+            ((weight - min_weight) / (max_weight - min_weight)).sqrt()
+        } else {
+            1.0
+        };
+        let font_size = MIN_FONT_SIZE + (MAX_FONT_SIZE - MIN_FONT_SIZE) * scale;
+
+        let mut word = PlacedWord {
+            width: text.chars().count() as f32 * font_size * CHARACTER_WIDTH,
+            height: font_size * LINE_HEIGHT,
+            text,
+            font_size,
+            color,
+            x: 0.0,
+            y: 0.0,
+        };
+
+        // Walk out along the spiral until the word fits, words that never fit are left out.
+        // The spiral is wider than it is tall so the cloud comes out landscape
+        for step in 0..MAX_SPIRAL_STEPS {
+            let angle = step as f32 * SPIRAL_STEP;
+            let radius = SPIRAL_SPACING * angle;
+            word.x = radius * angle.cos() * 1.5;
+            word.y = radius * angle.sin();
+
+            if !placed.iter().any(|other| word.overlaps(other)) {
+                placed.push(word);
+                break;
+            }
+        }
+    }
+
+    // Fit the image around the words
+    let left = placed
+        .iter()
+        .map(|word| word.x - word.width / 2.0)
+        .fold(0.0, f32::min)
+        - PADDING;
+    let right = placed
+        .iter()
+        .map(|word| word.x + word.width / 2.0)
+        .fold(0.0, f32::max)
+        + PADDING;
+    let top = placed
+        .iter()
+        .map(|word| word.y - word.height / 2.0)
+        .fold(0.0, f32::min)
+        - PADDING;
+    let mut bottom = placed
+        .iter()
+        .map(|word| word.y + word.height / 2.0)
+        .fold(0.0, f32::max)
+        + PADDING;
+
+    let legend_y = bottom + MIN_FONT_SIZE;
+    if cv.is_some() {
+        bottom += MIN_FONT_SIZE * 2.0;
+    }
+
+    let width = right - left;
+    let height = bottom - top;
+
+    let mut svg = String::new();
+    svg.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" height=\"{:.0}\" viewBox=\"{:.1} {:.1} {:.1} {:.1}\">\n",
+        width.ceil(),
+        height.ceil(),
+        left,
+        top,
+        width,
+        height
+    ));
+    svg.push_str(&format!(
+        "  <rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"white\"/>\n",
+        left, top, width, height
+    ));
+
+    for word in placed.iter() {
+        svg.push_str(&format!(
+            "  <text x=\"{:.1}\" y=\"{:.1}\" font-family=\"sans-serif\" font-size=\"{:.1}\" fill=\"{}\" text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>\n",
+            word.x,
+            word.y,
+            word.font_size,
+            word.color,
+            escape(&word.text)
+        ));
+    }
+
+    if cv.is_some() {
+        svg.push_str(&format!(
+            "  <text x=\"{:.1}\" y=\"{:.1}\" font-family=\"sans-serif\" font-size=\"{:.1}\"><tspan fill=\"{}\">Covered by the CV</tspan><tspan dx=\"20\" fill=\"{}\">Missing from the CV</tspan></text>\n",
+            left + PADDING,
+            legend_y,
+            MIN_FONT_SIZE,
+            COVERED_COLOR,
+            MISSING_COLOR
+        ));
+    }
+
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+// Writes the word cloud svg to a file
+pub fn write_word_cloud_svg(
+    word_cloud: &WordCloud,
+    cv: Option<&CV>,
+    output_file: String,
+) -> Result<()> {
+    let svg = render_word_cloud_svg(word_cloud, cv);
+
+    fs::write(&output_file, svg).map_err(|error| ResumeError::io(output_file, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The text elements of the svg with their font size, color and text
+    fn words(svg: &str) -> Vec<(f32, String, String)> {
+        svg.lines()
+            .filter(|line| line.contains("text-anchor"))
+            .map(|line| {
+                let attribute = |name: &str| {
+                    let start = line.find(&format!("{}=\"", name)).unwrap() + name.len() + 2;
+                    line[start..].split('"').next().unwrap().to_string()
+                };
+                let text = line.split('>').nth(1).unwrap().split('<').next().unwrap();
+                (
+                    attribute("font-size").parse().unwrap(),
+                    attribute("fill"),
+                    text.to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn font_size_grows_with_weight() {
+        let word_cloud = WordCloud::create_from_text("Rust, Rust, Rust and Kafka".to_string());
+        let svg = render_word_cloud_svg(&word_cloud, None);
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(!svg.contains("Covered by the CV"));
+        assert_eq!(
+            words(&svg),
+            vec![
+                (MAX_FONT_SIZE, NEUTRAL_COLOR.to_string(), "rust".to_string()),
+                (
+                    MIN_FONT_SIZE,
+                    NEUTRAL_COLOR.to_string(),
+                    "kafka".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn colors_keywords_by_whether_the_cv_covers_them() {
+        let word_cloud = WordCloud::create_from_text("Rust and Kafka".to_string());
+        let mut cv = CV::new();
+        cv.add_skill("Rust".to_string(), None);
+
+        let svg = render_word_cloud_svg(&word_cloud, Some(&cv));
+        let colors: Vec<(String, String)> = words(&svg)
+            .into_iter()
+            .map(|(_, color, text)| (text, color))
+            .collect();
+
+        assert!(colors.contains(&("rust".to_string(), COVERED_COLOR.to_string())));
+        assert!(colors.contains(&("kafka".to_string(), MISSING_COLOR.to_string())));
+        assert!(svg.contains("Covered by the CV"));
+    }
+
+    #[test]
+    fn only_draws_the_heaviest_keywords() {
+        let text: Vec<String> = (0..100).map(|i| format!("keyword{}x", i)).collect();
+        let word_cloud = WordCloud::create_from_text(text.join(", "));
+
+        assert_eq!(
+            words(&render_word_cloud_svg(&word_cloud, None)).len(),
+            MAX_WORDS
+        );
+        assert!(words(&render_word_cloud_svg(&WordCloud::new(), None)).is_empty());
+    }
+
+    #[test]
+    fn escapes_markup_in_keywords() {
+        assert_eq!(
+            escape("<c++ & \"c#\">'"),
+            "&lt;c++ &amp; &quot;c#&quot;&gt;&apos;"
+        );
+    }
+}
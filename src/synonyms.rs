@@ -0,0 +1,180 @@
+use crate::error::{Result, ResumeError};
+use crate::stopwords::strip_comment;
+use crate::tokenizer::split_words;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+};
+
+// Common aliases of technology names, the canonical name comes first
+const BUILTIN_SYNONYMS: &[(&str, &[&str])] = &[
+    ("Kubernetes", &["k8s", "kube"]),
+    ("PostgreSQL", &["postgres", "psql", "pgsql"]),
+    ("JavaScript", &["js", "ecmascript"]),
+    ("TypeScript", &["ts"]),
+    ("Go", &["golang"]),
+    ("Python", &["python3"]),
+    ("C++", &["cpp"]),
+    ("C#", &["csharp"]),
+    (".NET", &["dotnet"]),
+    ("Node.js", &["nodejs"]),
+    ("React", &["reactjs", "react.js"]),
+    ("Vue.js", &["vue", "vuejs"]),
+    ("Angular", &["angularjs"]),
+    ("MongoDB", &["mongo"]),
+    ("Objective-C", &["objc"]),
+    ("CI/CD", &["cicd"]),
+    ("AWS", &["amazon web services"]),
+    ("GCP", &["google cloud platform"]),
+];
+
+// Maps aliases of a skill or keyword to one canonical name, so "k8s" in a job
+// description matches "Kubernetes" in the CV
+#[derive(Clone, Default)]
+pub struct Synonyms {
+    // Words of the alias joined by spaces to canonical name
+    aliases: HashMap<String, String>,
+    // Number of words in the longest alias
+    longest_alias: usize,
+    // Every word of every canonical name
+    canonical_words: HashSet<String>,
+}
+
+impl Synonyms {
+    // No synonyms, every word only matches itself
+    pub fn new() -> Self {
+        Self {
+            aliases: HashMap::new(),
+            longest_alias: 0,
+            canonical_words: HashSet::new(),
+        }
+    }
+
+    // The built in list of technology aliases
+    pub fn builtin() -> Self {
+        let mut synonyms = Self::new();
+        for (canonical, aliases) in BUILTIN_SYNONYMS.iter() {
+            for alias in aliases.iter() {
+                synonyms.add(canonical.to_string(), alias);
+            }
+        }
+        synonyms
+    }
+
+    // Reads synonyms from a file with one canonical name per line followed by
+    // a colon and its comma separated aliases, like "Kubernetes: k8s, kube".
+    // A # at the start of a line or after whitespace starts a comment
+    pub fn load_from_file(filename: String) -> Result<Self> {
+        let contents =
+            fs::read_to_string(&filename).map_err(|error| ResumeError::io(filename, error))?;
+
+        let mut synonyms = Self::new();
+        for line in contents.lines() {
+            let line = strip_comment(line);
+            let Some((canonical, aliases)) = line.split_once(':') else {
+                continue;
+            };
+
+            let canonical = canonical.trim();
+            if canonical.is_empty() {
+                continue;
+            }
+            for alias in aliases
+                .split(',')
+                .map(str::trim)
+                .filter(|alias| !alias.is_empty())
+            {
+                synonyms.add(canonical.to_string(), alias);
+            }
+        }
+
+        Ok(synonyms)
+    }
+
+    // Aliases are stored as the words the tokenizer splits them into, so
+    // "Amazon Web Services" matches however it is spaced or capitalized
+    pub fn add(&mut self, canonical: String, alias: &str) {
+        let words: Vec<String> = split_words(alias).collect();
+        if words.is_empty() {
+            return;
+        }
+
+        self.longest_alias = self.longest_alias.max(words.len());
+        self.canonical_words.extend(split_words(&canonical));
+        self.aliases.insert(words.join(" "), canonical);
+    }
+
+    // Adds the other synonyms, replacing the canonical name of aliases both have
+    pub fn extend(&mut self, other: Synonyms) {
+        self.longest_alias = self.longest_alias.max(other.longest_alias);
+        self.canonical_words.extend(other.canonical_words);
+        self.aliases.extend(other.aliases);
+    }
+
+    pub fn get_canonical(&self, alias: &str) -> Option<&String> {
+        self.aliases
+            .get(&split_words(alias).collect::<Vec<String>>().join(" "))
+    }
+
+    // The canonical name of an alias given as the words the tokenizer split it into
+    pub fn get_canonical_of_words(&self, words: &[String]) -> Option<&String> {
+        self.aliases.get(&words.join(" "))
+    }
+
+    // Whether the word is part of a canonical name, like "kubernetes" or "aws"
+    pub fn is_canonical_word(&self, word: &str) -> bool {
+        self.canonical_words.contains(word)
+    }
+
+    pub fn get_longest_alias(&self) -> usize {
+        self.longest_alias
+    }
+
+    // The canonical name of an alias, or the name itself if it is not an alias
+    pub fn normalize(&self, name: &str) -> String {
+        match self.get_canonical(name.trim()) {
+            Some(canonical) => canonical.clone(),
+            None => name.to_string(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.aliases.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn synonym_files_keep_hashes_inside_names() {
+        let filename = std::env::temp_dir().join("resume_builder_synonyms.txt");
+        fs::write(
+            &filename,
+            "# languages\nC#: csharp\nF#: fsharp, f-sharp # functional\n",
+        )
+        .unwrap();
+
+        let synonyms = Synonyms::load_from_file(filename.to_string_lossy().to_string()).unwrap();
+        fs::remove_file(&filename).unwrap();
+
+        assert_eq!(synonyms.len(), 3);
+        assert_eq!(synonyms.normalize("CSharp"), "C#");
+        assert_eq!(synonyms.normalize("f-sharp"), "F#");
+    }
+
+    #[test]
+    fn aliases_match_however_they_are_spaced() {
+        let synonyms = Synonyms::builtin();
+
+        assert_eq!(synonyms.normalize("Amazon  Web Services"), "AWS");
+        assert_eq!(synonyms.normalize("K8s"), "Kubernetes");
+        assert_eq!(synonyms.normalize("Rust"), "Rust");
+        assert_eq!(synonyms.get_longest_alias(), 3);
+    }
+}
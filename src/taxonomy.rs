@@ -0,0 +1,396 @@
+use crate::error::{Result, ResumeError};
+use crate::gap::KeywordLocation;
+use crate::resume::CV;
+use crate::tokenizer::Tokenizer;
+use serde::Deserialize;
+use std::{collections::HashSet, fmt::Display, fs};
+
+// One row of the taxonomy csv, aliases are separated by ; | or ,
+#[derive(Deserialize)]
+struct TaxonomyRecord {
+    skill: String,
+    category: String,
+    #[serde(default)]
+    aliases: String,
+}
+
+// A skill from the taxonomy and the other names it goes by
+pub struct TaxonomySkill {
+    name: String,
+    category: String,
+    aliases: Vec<String>,
+}
+
+impl TaxonomySkill {
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_category(&self) -> &String {
+        &self.category
+    }
+
+    pub fn get_aliases(&self) -> &Vec<String> {
+        &self.aliases
+    }
+
+    fn names(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.name).chain(self.aliases.iter())
+    }
+}
+
+// A list of known skills grouped into categories, like an ESCO or O*NET export
+#[derive(Default)]
+pub struct Taxonomy {
+    skills: Vec<TaxonomySkill>,
+}
+
+impl Taxonomy {
+    pub fn new() -> Self {
+        Self { skills: Vec::new() }
+    }
+
+    // Reads a csv file with a "skill,category,aliases" header, the aliases column is optional
+    pub fn load_from_file(filename: String) -> Result<Self> {
+        let contents = fs::read_to_string(&filename)
+            .map_err(|error| ResumeError::io(filename.clone(), error))?;
+
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .flexible(true)
+            .from_reader(contents.as_bytes());
+
+        let csv_error = |source| ResumeError::Csv {
+            filename: filename.clone(),
+            source,
+        };
+        let headers = reader.headers().map_err(csv_error)?.clone();
+
+        let mut taxonomy = Self::new();
+        for record in reader.records() {
+            // Rows that leave out the aliases are padded, serde does not default
+            // a field that is missing from the end of a row
+            let mut record = record.map_err(csv_error)?;
+            while record.len() < headers.len() {
+                record.push_field("");
+            }
+            let record: TaxonomyRecord = record.deserialize(Some(&headers)).map_err(csv_error)?;
+
+            let aliases = record
+                .aliases
+                .split([';', '|', ','])
+                .map(str::trim)
+                .filter(|alias| !alias.is_empty())
+                .map(str::to_string)
+                .collect();
+            taxonomy.add_skill(record.skill, record.category, aliases);
+        }
+
+        Ok(taxonomy)
+    }
+
+    pub fn add_skill(&mut self, name: String, category: String, aliases: Vec<String>) {
+        if !name.is_empty() {
+            self.skills.push(TaxonomySkill {
+                name,
+                category,
+                aliases,
+            });
+        }
+    }
+
+    pub fn get_skills(&self) -> &Vec<TaxonomySkill> {
+        &self.skills
+    }
+
+    pub fn len(&self) -> usize {
+        self.skills.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.skills.is_empty()
+    }
+}
+
+// A taxonomy skill the CV uses in its bullets but does not list
+pub struct SkillSuggestion {
+    skill: String,
+    category: String,
+    locations: Vec<KeywordLocation>,
+}
+
+impl SkillSuggestion {
+    pub fn get_skill(&self) -> &String {
+        &self.skill
+    }
+
+    pub fn get_category(&self) -> &String {
+        &self.category
+    }
+
+    pub fn get_locations(&self) -> &Vec<KeywordLocation> {
+        &self.locations
+    }
+}
+
+// Skills worth adding to the CV, sorted by category and then name
+pub struct SkillSuggestions {
+    suggestions: Vec<SkillSuggestion>,
+}
+
+impl SkillSuggestions {
+    // Looks for every taxonomy skill, under any of its names, in the work experience
+    // and project bullets of the CV. Skills the CV already lists under any name are
+    // left out. Text is compared with the tokenizer so stemming and synonyms apply,
+    // but skill names are never stemmed so "excellent" does not match "Excel"
+    pub fn new(cv: &CV, taxonomy: &Taxonomy, tokenizer: &Tokenizer) -> Self {
+        let mut tokenizer = tokenizer.clone();
+        tokenizer.keep_names(cv.get_skills().get_skill_tree().values().flatten());
+        tokenizer.keep_names(taxonomy.skills.iter().flat_map(TaxonomySkill::names));
+
+        let listed: HashSet<Vec<String>> = cv
+            .get_skills()
+            .get_skill_tree()
+            .values()
+            .flatten()
+            .map(|skill| tokenizer.tokenize(skill))
+            .collect();
+
+        let mut sections: Vec<(KeywordLocation, &Vec<String>)> = Vec::new();
+        for experience in cv.get_work_experience() {
+            let location = KeywordLocation::WorkExperience {
+                job_title: experience.get_job_title().clone(),
+            };
+            sections.push((location, experience.get_job_description()));
+        }
+        for project in cv.get_projects() {
+            let location = KeywordLocation::Project {
+                name: project.get_project_name().clone(),
+            };
+            sections.push((location, project.get_project_description()));
+        }
+
+        // Clauses are matched one at a time so a skill never spans two sentences
+        let section_clauses: Vec<(KeywordLocation, Vec<Vec<String>>)> = sections
+            .into_iter()
+            .map(|(location, bullets)| {
+                let clauses = bullets
+                    .iter()
+                    .flat_map(|bullet| tokenizer.tokenize_clauses(bullet))
+                    .collect();
+                (location, clauses)
+            })
+            .collect();
+
+        let mut suggestions = Vec::new();
+        for skill in taxonomy.get_skills() {
+            let names: Vec<Vec<String>> = skill
+                .names()
+                .map(|name| tokenizer.tokenize(name))
+                .filter(|tokens| !tokens.is_empty())
+                .collect();
+            if names.is_empty() || names.iter().any(|name| listed.contains(name)) {
+                continue;
+            }
+
+            let locations: Vec<KeywordLocation> = section_clauses
+                .iter()
+                .filter(|(_, clauses)| {
+                    clauses.iter().any(|clause| {
+                        names.iter().any(|name| {
+                            clause
+                                .windows(name.len())
+                                .any(|window| window == name.as_slice())
+                        })
+                    })
+                })
+                .map(|(location, _)| location.clone())
+                .collect();
+
+            if !locations.is_empty() {
+                suggestions.push(SkillSuggestion {
+                    skill: skill.name.clone(),
+                    category: skill.category.clone(),
+                    locations,
+                });
+            }
+        }
+
+        suggestions.sort_by(|a, b| {
+            a.category
+                .to_lowercase()
+                .cmp(&b.category.to_lowercase())
+                .then_with(|| a.skill.to_lowercase().cmp(&b.skill.to_lowercase()))
+        });
+
+        Self { suggestions }
+    }
+
+    pub fn get_suggestions(&self) -> &Vec<SkillSuggestion> {
+        &self.suggestions
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.suggestions.is_empty()
+    }
+
+    // Adds every suggested skill to the CV under its taxonomy category
+    pub fn add_to(&self, cv: &mut CV) {
+        for suggestion in self.suggestions.iter() {
+            cv.add_skill(suggestion.skill.clone(), Some(suggestion.category.clone()));
+        }
+    }
+}
+
+impl Display for SkillSuggestions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.suggestions.is_empty() {
+            return writeln!(f, "No skills to suggest");
+        }
+
+        writeln!(f, "Suggested skills:")?;
+
+        let mut category: Option<&String> = None;
+        for suggestion in self.suggestions.iter() {
+            if category != Some(&suggestion.category) {
+                writeln!(f, "    {}", suggestion.category)?;
+                category = Some(&suggestion.category);
+            }
+
+            let locations: Vec<String> = suggestion
+                .locations
+                .iter()
+                .map(|location| location.to_string())
+                .collect();
+            writeln!(
+                f,
+                "        {} in {}",
+                suggestion.skill,
+                locations.join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resume::Date;
+    use crate::stemming::StemmingLanguage;
+    use crate::synonyms::Synonyms;
+
+    fn load(contents: &str) -> Taxonomy {
+        let filename = std::env::temp_dir().join("resume_builder_taxonomy.csv");
+        fs::write(&filename, contents).unwrap();
+        let taxonomy = Taxonomy::load_from_file(filename.to_string_lossy().to_string());
+        fs::remove_file(&filename).unwrap();
+
+        taxonomy.unwrap()
+    }
+
+    fn suggested(suggestions: &SkillSuggestions) -> Vec<(&str, &str)> {
+        suggestions
+            .get_suggestions()
+            .iter()
+            .map(|suggestion| {
+                (
+                    suggestion.get_category().as_str(),
+                    suggestion.get_skill().as_str(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reads_rows_with_and_without_aliases() {
+        let taxonomy = load(
+            "skill,category,aliases\n\
+             Kubernetes, DevOps, k8s;kube\n\
+             Terraform,DevOps\n\
+             PostgreSQL,Databases,\"postgres| psql, pgsql\"\n\
+             ,Empty,nothing\n",
+        );
+
+        let skills: Vec<(&str, &str, Vec<&str>)> = taxonomy
+            .get_skills()
+            .iter()
+            .map(|skill| {
+                (
+                    skill.get_name().as_str(),
+                    skill.get_category().as_str(),
+                    skill.get_aliases().iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            skills,
+            vec![
+                ("Kubernetes", "DevOps", vec!["k8s", "kube"]),
+                ("Terraform", "DevOps", vec![]),
+                ("PostgreSQL", "Databases", vec!["postgres", "psql", "pgsql"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_files_are_csv_errors() {
+        let filename = std::env::temp_dir().join("resume_builder_bad_taxonomy.csv");
+        fs::write(&filename, "name,group\nRust,Languages\n").unwrap();
+        let result = Taxonomy::load_from_file(filename.to_string_lossy().to_string());
+        fs::remove_file(&filename).unwrap();
+
+        assert!(matches!(result, Err(ResumeError::Csv { .. })));
+    }
+
+    #[test]
+    fn suggests_skills_the_bullets_mention_under_any_name() {
+        let mut taxonomy = Taxonomy::new();
+        taxonomy.add_skill("Kubernetes".to_string(), "DevOps".to_string(), vec![]);
+        taxonomy.add_skill(
+            "Terraform".to_string(),
+            "DevOps".to_string(),
+            vec!["tf".to_string()],
+        );
+        taxonomy.add_skill("Excel".to_string(), "Office".to_string(), vec![]);
+        taxonomy.add_skill("Apache Kafka".to_string(), "Data".to_string(), vec![]);
+        taxonomy.add_skill("Rust".to_string(), "Languages".to_string(), vec![]);
+
+        let mut cv = CV::new();
+        cv.add_skill("Rust".to_string(), Some("Languages".to_string()));
+        cv.add_work_experience(
+            "Engineer".to_string(),
+            "Acme".to_string(),
+            None,
+            vec![
+                "Ran Rust services on k8s with TF".to_string(),
+                "Excellent reviews. Apache, then Kafka".to_string(),
+            ],
+            Date::new(2020, Some(1), None),
+            None,
+        );
+
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.set_synonyms(Synonyms::builtin());
+        tokenizer.set_stemming(Some(StemmingLanguage::English));
+
+        // Rust is already listed, Excel only shows up as "excellent" and
+        // "Apache Kafka" is split across two clauses
+        let suggestions = SkillSuggestions::new(&cv, &taxonomy, &tokenizer);
+        assert_eq!(
+            suggested(&suggestions),
+            vec![("DevOps", "Kubernetes"), ("DevOps", "Terraform")]
+        );
+        assert_eq!(
+            suggestions.get_suggestions()[0].get_locations(),
+            &vec![KeywordLocation::WorkExperience {
+                job_title: "Engineer".to_string()
+            }]
+        );
+
+        suggestions.add_to(&mut cv);
+        let listed = SkillSuggestions::new(&cv, &taxonomy, &tokenizer);
+        assert!(listed.is_empty());
+    }
+}
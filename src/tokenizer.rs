@@ -0,0 +1,328 @@
+use crate::stemming::StemmingLanguage;
+use crate::stopwords::Stopwords;
+use crate::synonyms::Synonyms;
+use rust_stemmers::Stemmer;
+use std::collections::HashSet;
+
+// Splits text into lowercase words, the same way for job descriptions and resume text.
+//
+// Punctuation around words is dropped, but punctuation that is part of a technology
+// name is kept so "C++", "C#", ".NET", "Node.js" and "CI/CD" stay whole.
+// Stopwords are left out so they never count for or against anything, aliases
+// are replaced with their canonical name, and words can optionally be stemmed
+// so different forms of a word match. Canonical names and kept names like the
+// skills in a CV are never stemmed, and no other word is stemmed into one of them,
+// so "excellent" does not turn into "excel".
+#[derive(Default)]
+pub struct Tokenizer {
+    stopwords: Stopwords,
+    synonyms: Synonyms,
+    stemming: Option<StemmingLanguage>,
+    stemmer: Option<Stemmer>,
+    // Words of the kept names
+    kept_words: HashSet<String>,
+}
+
+impl Clone for Tokenizer {
+    fn clone(&self) -> Self {
+        let mut tokenizer = Self::new();
+        tokenizer.set_stopwords(self.stopwords.clone());
+        tokenizer.set_synonyms(self.synonyms.clone());
+        tokenizer.set_stemming(self.stemming);
+        tokenizer.kept_words = self.kept_words.clone();
+        tokenizer
+    }
+}
+
+// Characters that do not split a word because they can be part of a technology name
+const WORD_PUNCTUATION: [char; 6] = ['+', '#', '.', '/', '\'', '’'];
+
+// Names that are one word with a slash in them, any other slash separates
+// two words like "Python/Java" or "C/C++"
+const SLASH_WORDS: [&str; 6] = ["ci/cd", "tcp/ip", "pl/sql", "i/o", "ui/ux", "a/b"];
+
+impl Tokenizer {
+    pub fn new() -> Self {
+        Self {
+            stopwords: Stopwords::new(),
+            synonyms: Synonyms::new(),
+            stemming: None,
+            stemmer: None,
+            kept_words: HashSet::new(),
+        }
+    }
+
+    pub fn get_stopwords(&self) -> &Stopwords {
+        &self.stopwords
+    }
+
+    pub fn set_stopwords(&mut self, stopwords: Stopwords) {
+        self.stopwords = stopwords;
+    }
+
+    pub fn get_synonyms(&self) -> &Synonyms {
+        &self.synonyms
+    }
+
+    pub fn set_synonyms(&mut self, synonyms: Synonyms) {
+        self.synonyms = synonyms;
+    }
+
+    pub fn get_stemming(&self) -> Option<StemmingLanguage> {
+        self.stemming
+    }
+
+    // None turns stemming off
+    pub fn set_stemming(&mut self, stemming: Option<StemmingLanguage>) {
+        self.stemming = stemming;
+        self.stemmer = stemming.map(|language| language.create_stemmer());
+    }
+
+    // Names whose words are never stemmed, like skill names that stemming
+    // would otherwise mix up with other words
+    pub fn keep_names<S: AsRef<str>>(&mut self, names: impl IntoIterator<Item = S>) {
+        for name in names {
+            self.kept_words.extend(split_words(name.as_ref()));
+        }
+    }
+
+    // Tokenizes each clause of the text on its own, so phrases can be found
+    // without joining words from different sentences or list items
+    pub fn tokenize_clauses(&self, text: &str) -> Vec<Vec<String>> {
+        split_clauses(text)
+            .map(|clause| self.tokenize(clause))
+            .filter(|tokens| !tokens.is_empty())
+            .collect()
+    }
+
+    // Like tokenize_clauses, but with the word each token was stemmed from
+    pub fn tokenize_clauses_with_words(&self, text: &str) -> Vec<Vec<(String, String)>> {
+        split_clauses(text)
+            .map(|clause| self.tokenize_with_words(clause))
+            .filter(|tokens| !tokens.is_empty())
+            .collect()
+    }
+
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        self.tokenize_with_words(text)
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect()
+    }
+
+    // Every token with the lowercase word it was stemmed from, so stems can be
+    // shown as a word people recognize
+    pub fn tokenize_with_words(&self, text: &str) -> Vec<(String, String)> {
+        self.resolve_synonyms(split_words(text).collect())
+            .into_iter()
+            .filter(|word| !self.stopwords.contains(word))
+            .map(|word| (self.stem(&word), word))
+            .collect()
+    }
+
+    // Aliases are replaced with the words of their canonical name, so "k8s" becomes
+    // "kubernetes" and "amazon web services" becomes "aws". The longest alias
+    // starting at a word wins
+    fn resolve_synonyms(&self, words: Vec<String>) -> Vec<String> {
+        if self.synonyms.is_empty() {
+            return words;
+        }
+
+        let mut resolved = Vec::new();
+        let mut i = 0;
+        while i < words.len() {
+            let longest = self.synonyms.get_longest_alias().min(words.len() - i);
+            let alias = (1..=longest).rev().find_map(|length| {
+                self.synonyms
+                    .get_canonical_of_words(&words[i..i + length])
+                    .map(|canonical| (length, canonical))
+            });
+
+            match alias {
+                Some((length, canonical)) => {
+                    resolved.extend(split_words(canonical));
+                    i += length;
+                }
+                None => {
+                    resolved.push(words[i].clone());
+                    i += 1;
+                }
+            }
+        }
+
+        resolved
+    }
+
+    // Only plain words are stemmed, technology names like "node.js" or "c++" are left alone
+    fn stem(&self, word: &str) -> String {
+        match &self.stemmer {
+            Some(stemmer) if word.chars().all(char::is_alphabetic) && !self.is_kept(word) => {
+                let stem = stemmer.stem(word);
+                if self.is_kept(&stem) {
+                    word.to_string()
+                } else {
+                    stem.to_string()
+                }
+            }
+            _ => word.to_string(),
+        }
+    }
+
+    fn is_kept(&self, word: &str) -> bool {
+        self.kept_words.contains(word) || self.synonyms.is_canonical_word(word)
+    }
+}
+
+// Splits text into cleaned, lowercase words
+pub(crate) fn split_words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric() && !WORD_PUNCTUATION.contains(&c))
+        .filter_map(clean_token)
+        .flat_map(|word| {
+            if word.contains('/') && !SLASH_WORDS.contains(&word.as_str()) {
+                word.split('/').filter_map(clean_token).collect()
+            } else {
+                vec![word]
+            }
+        })
+}
+
+// Splits text on line breaks and punctuation that ends a clause. A dot only
+// ends a clause when followed by whitespace so names like "node.js" stay whole
+fn split_clauses(text: &str) -> impl Iterator<Item = &str> {
+    let mut clauses = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        let ends_clause = match c {
+            '\n' | '!' | '?' | ';' | ':' | ',' | '(' | ')' | '•' => true,
+            '.' => chars.peek().is_none_or(|(_, next)| next.is_whitespace()),
+            _ => false,
+        };
+
+        if ends_clause {
+            clauses.push(&text[start..i]);
+            start = i + c.len_utf8();
+        }
+    }
+    clauses.push(&text[start..]);
+
+    clauses.into_iter()
+}
+
+// Trims the punctuation that is not part of the word and lowercases it,
+// returns None if there is no word left
+fn clean_token(token: &str) -> Option<String> {
+    let token = token.replace('’', "'").to_lowercase();
+
+    // Leading punctuation is dropped unless it is a dot starting a name like ".net"
+    let mut start = token.trim_start_matches(|c: char| !c.is_alphanumeric());
+    let leading = &token[..token.len() - start.len()];
+    if leading.ends_with('.') && start.starts_with(char::is_alphabetic) && !start.contains('.') {
+        start = &token[token.len() - start.len() - 1..];
+    }
+
+    // Trailing dots, slashes and quotes end a sentence or a path, they are never part of the word
+    let mut word = start.trim_end_matches(['.', '/', '\'']);
+
+    // Trailing + and # are only kept after a letter, like "c++" or "c#"
+    let without_suffix = word.trim_end_matches(['+', '#']);
+    if without_suffix.len() != word.len() && !without_suffix.ends_with(char::is_alphabetic) {
+        word = without_suffix.trim_end_matches(|c: char| !c.is_alphanumeric());
+    }
+
+    // Drop possessives
+    let word = word.strip_suffix("'s").unwrap_or(word);
+
+    if word.chars().any(char::is_alphabetic) {
+        Some(word.to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokenize(text: &str) -> Vec<String> {
+        Tokenizer::new().tokenize(text)
+    }
+
+    #[test]
+    fn keeps_technology_names_whole() {
+        assert_eq!(
+            tokenize("C++, C#, .NET, Node.js and CI/CD"),
+            vec!["c++", "c#", ".net", "node.js", "and", "ci/cd"]
+        );
+    }
+
+    #[test]
+    fn drops_punctuation_around_words() {
+        assert_eq!(
+            tokenize("Rust, (Go) \"Python\"."),
+            vec!["rust", "go", "python"]
+        );
+        assert_eq!(tokenize("Rust's compiler."), vec!["rust", "compiler"]);
+    }
+
+    #[test]
+    fn splits_words_joined_by_a_slash() {
+        assert_eq!(
+            tokenize("Python/Java and C/C++"),
+            vec!["python", "java", "and", "c", "c++"]
+        );
+        assert_eq!(
+            tokenize("front-end/back-end"),
+            vec!["front", "end", "back", "end"]
+        );
+    }
+
+    #[test]
+    fn replaces_aliases_with_their_canonical_name() {
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.set_synonyms(Synonyms::builtin());
+
+        assert_eq!(
+            tokenizer.tokenize("k8s and Golang"),
+            vec!["kubernetes", "and", "go"]
+        );
+        assert_eq!(
+            tokenizer.tokenize("Amazon Web Services, Google Cloud"),
+            vec!["aws", "google", "cloud"]
+        );
+    }
+
+    #[test]
+    fn never_stems_into_kept_names() {
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.set_synonyms(Synonyms::builtin());
+        tokenizer.set_stemming(Some(StemmingLanguage::English));
+        tokenizer.keep_names(["Excel"]);
+
+        assert_eq!(
+            tokenizer.tokenize("Excellent Kubernetes and Excel developers"),
+            vec!["excellent", "kubernetes", "and", "excel", "develop"]
+        );
+        assert_eq!(
+            tokenizer.tokenize_with_words("developers"),
+            vec![("develop".to_string(), "developers".to_string())]
+        );
+    }
+
+    #[test]
+    fn lowercases_unicode() {
+        assert_eq!(tokenize("Élan ÜBER"), vec!["élan", "über"]);
+    }
+
+    #[test]
+    fn leaves_out_stopwords() {
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.set_stopwords(Stopwords::english());
+
+        assert_eq!(
+            tokenizer.tokenize("Experience with the Rust language"),
+            vec!["experience", "rust", "language"]
+        );
+    }
+}
@@ -1,25 +1,66 @@
-use std::{collections::HashMap, fmt::Display, fs};
+use crate::error::{Result, ResumeError};
+use crate::job_description::{load_job_description, JobDescription, SectionWeights};
+use crate::scorer::sum_or_zero;
+use crate::stopwords::{strip_comment, Stopwords};
+use crate::synonyms::Synonyms;
+use crate::tokenizer::Tokenizer;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    fs,
+    path::PathBuf,
+};
 
+// Bigrams and trigrams are counted, longer phrases only when they are in the phrase list
+const MAX_NGRAM_LENGTH: usize = 3;
+// Bigrams and trigrams that are not in the phrase list have to be repeated to be
+// a keyword, otherwise every pair of words next to each other would be one
+const MIN_NGRAM_COUNT: i32 = 2;
+
+#[derive(Clone)]
 pub struct WordCloud {
     //                  Word
     //                    |   Frequency
     //                    V      V
-    word_cloud: HashMap<String, i32>,
-    total_weight: i32
+    word_cloud: HashMap<String, f32>,
+    total_weight: f32,
+    // Number of times every bigram, trigram and listed phrase appears and its frequency,
+    // words are separated by a space
+    phrase_cloud: HashMap<String, (i32, f32)>,
+    // Curated phrases that count as keywords even if they only appear once
+    phrases: HashSet<String>,
+    longest_phrase: usize,
+    // How many corpus documents each word and phrase appears in, used to weight
+    // words by TF-IDF. Empty when there is no corpus so every word keeps its frequency
+    document_frequency: HashMap<String, i32>,
+    document_count: i32,
+    // When set, text is split into sections and each section's words count as
+    // much as its multiplier, otherwise every word counts once
+    section_weights: Option<SectionWeights>,
+    // How often each word was seen in the text for every word in the cloud, so
+    // stemmed words can be shown as the word they most often came from
+    word_forms: HashMap<String, HashMap<String, i32>>,
+    // Splits both the job description and the text being scored
+    tokenizer: Tokenizer,
 }
 
 pub struct WordScore {
-    word_weight: i32,
-    total_weight: i32,
-    score_ratio: f32
+    word_weight: f32,
+    total_weight: f32,
+    score_ratio: f32,
 }
 
 impl WordScore {
-    pub fn new(word_weight: i32, total_weight: i32) -> Self {
+    // An empty word cloud has no total weight, nothing in it scores anything
+    pub fn new(word_weight: f32, total_weight: f32) -> Self {
         Self {
             word_weight,
             total_weight,
-            score_ratio: word_weight as f32 / total_weight as f32
+            score_ratio: if total_weight == 0.0 {
+                0.0
+            } else {
+                word_weight / total_weight
+            },
         }
     }
 
@@ -28,89 +69,600 @@ impl WordScore {
         self.score_ratio
     }
 
-    pub fn get_word_weight(&self) -> i32 {
+    pub fn get_word_weight(&self) -> f32 {
         self.word_weight
     }
 }
 
 impl Display for WordScore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Word Weight: {} / {} = {}", self.word_weight, self.total_weight, self.score_ratio)?;
-        
+        write!(
+            f,
+            "Word Weight: {} / {} = {}",
+            self.word_weight, self.total_weight, self.score_ratio
+        )?;
+
         Ok(())
     }
 }
 
+impl Default for WordCloud {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl WordCloud {
-    #[allow(unused)]
+    // An empty word cloud that leaves out the built in English stopwords, matches
+    // the built in aliases and weights job description sections by default
     pub fn new() -> Self {
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.set_stopwords(Stopwords::english());
+        tokenizer.set_synonyms(Synonyms::builtin());
+
         Self {
             word_cloud: HashMap::new(),
-            total_weight: 0
+            total_weight: 0.0,
+            phrase_cloud: HashMap::new(),
+            phrases: HashSet::new(),
+            longest_phrase: MAX_NGRAM_LENGTH,
+            document_frequency: HashMap::new(),
+            document_count: 0,
+            section_weights: Some(SectionWeights::default()),
+            word_forms: HashMap::new(),
+            tokenizer,
         }
     }
 
     #[allow(unused)]
-    pub fn create_from_text_file(filename: String) -> Self {
-        // Reads from a file and removes extra spaces and new lines and then collects it into a vector
-        let mut binding = fs::read_to_string(filename)
-        .unwrap()
-        .trim().to_string()
-        .to_lowercase();
+    pub fn create_from_text_file(filename: String) -> Result<Self> {
+        Self::create_from_text_file_with_stopwords(filename, Stopwords::english())
+    }
 
-        binding.retain(|c| c.is_alphabetic() || c.is_whitespace());
-        
-        let contents = binding
-            .split_whitespace()
-            .collect::<Vec<&str>>();
+    pub fn create_from_text(text: String) -> Self {
+        Self::create_from_text_with_stopwords(text, Stopwords::english())
+    }
 
-        let mut string_contents: Vec<String> = Vec::new();
+    // Text, html and pdf job descriptions can all be loaded
+    pub fn create_from_text_file_with_stopwords(
+        filename: String,
+        stopwords: Stopwords,
+    ) -> Result<Self> {
+        let text = load_job_description(filename)?;
 
-        for content in contents {
-            string_contents.push(content.to_string());
+        Ok(Self::create_from_text_with_stopwords(text, stopwords))
+    }
+
+    // Stopwords are left out of the cloud and do not add to the total weight,
+    // and are skipped again when scoring text against the cloud
+    pub fn create_from_text_with_stopwords(text: String, stopwords: Stopwords) -> Self {
+        let mut ret = Self::new();
+        ret.tokenizer.set_stopwords(stopwords);
+        ret.add_text(&text);
+
+        ret
+    }
+
+    // An empty word cloud that splits text with the given tokenizer, so its
+    // stopwords and stemming are used both when adding text and when scoring.
+    // Sections are weighted by default like in WordCloud::new
+    pub fn with_tokenizer(tokenizer: Tokenizer) -> Self {
+        Self {
+            word_cloud: HashMap::new(),
+            total_weight: 0.0,
+            phrase_cloud: HashMap::new(),
+            phrases: HashSet::new(),
+            longest_phrase: MAX_NGRAM_LENGTH,
+            document_frequency: HashMap::new(),
+            document_count: 0,
+            section_weights: Some(SectionWeights::default()),
+            word_forms: HashMap::new(),
+            tokenizer,
         }
+    }
 
+    // Text, html and pdf job descriptions can all be added
+    pub fn add_text_file(&mut self, filename: String) -> Result<()> {
+        let text = load_job_description(filename)?;
+        self.add_text(&text);
+
+        Ok(())
+    }
+
+    // Section weights only apply to text added after them, None counts every word once
+    pub fn set_section_weights(&mut self, section_weights: Option<SectionWeights>) {
+        self.section_weights = section_weights;
+    }
+
+    // Phrases only count as phrases in text added after them
+    pub fn add_phrase(&mut self, phrase: &str) {
+        let tokens = self.tokenizer.tokenize(phrase);
+        if tokens.len() > 1 {
+            self.longest_phrase = self.longest_phrase.max(tokens.len());
+            self.phrases.insert(tokens.join(" "));
+        }
+    }
+
+    // Reads a phrase list with one phrase per line, a # at the start of a line
+    // or after whitespace starts a comment
+    pub fn add_phrases_from_file(&mut self, filename: String) -> Result<()> {
+        let contents =
+            fs::read_to_string(&filename).map_err(|error| ResumeError::io(filename, error))?;
+        for line in contents.lines() {
+            self.add_phrase(strip_comment(line));
+        }
+
+        Ok(())
+    }
+
+    // An empty word cloud weighted by how rare words are across the job descriptions
+    // in a directory, text added to it is weighted by TF-IDF
+    pub fn from_corpus(dir: String) -> Result<Self> {
         let mut ret = Self::new();
-        for string in string_contents {
-            match ret.word_cloud.get_mut(&string) {
-                Some(value) => {
-                    *value += 1;
-                },
-                None => {
-                    ret.word_cloud.insert(string, 1);
-                },
+        ret.load_corpus(dir)?;
+
+        Ok(ret)
+    }
+
+    // Counts the documents each word and phrase appears in for every file in the
+    // directory. Phrases should be added first so they are counted too
+    pub fn load_corpus(&mut self, dir: String) -> Result<()> {
+        for file in list_files(dir)? {
+            let text = load_job_description(file.to_string_lossy().to_string())?;
+
+            let mut terms = HashSet::new();
+            for clause in self.tokenizer.tokenize_clauses(&text) {
+                terms.extend(self.ngrams(&clause));
+                terms.extend(clause);
             }
-            ret.total_weight += 1;
+
+            for term in terms {
+                *self.document_frequency.entry(term).or_insert(0) += 1;
+            }
+            self.document_count += 1;
         }
 
-        ret
+        Ok(())
+    }
+
+    // Every bigram and trigram in the clause, and any longer listed phrases
+    fn ngrams(&self, clause: &[String]) -> Vec<String> {
+        let mut ngrams = Vec::new();
+        for length in 2..=self.longest_phrase.min(clause.len()) {
+            for ngram in clause.windows(length) {
+                let ngram = ngram.join(" ");
+                if length <= MAX_NGRAM_LENGTH || self.phrases.contains(&ngram) {
+                    ngrams.push(ngram);
+                }
+            }
+        }
+
+        ngrams
+    }
+
+    // Smoothed inverse document frequency, 1 when there is no corpus.
+    // Words in every document get the lowest weight, words in none of them the highest
+    fn inverse_document_frequency(&self, term: &str) -> f32 {
+        if self.document_count == 0 {
+            return 1.0;
+        }
+
+        let document_frequency = *self.document_frequency.get(term).unwrap_or(&0);
+        ((1 + self.document_count) as f32 / (1 + document_frequency) as f32).ln() + 1.0
+    }
+
+    // With section weights the text is parsed as a job description first,
+    // so repeated paragraphs only count once
+    pub fn add_text(&mut self, text: &str) {
+        match self.section_weights.take() {
+            Some(section_weights) => {
+                self.add_job_description(&JobDescription::parse(text), &section_weights);
+                self.section_weights = Some(section_weights);
+            }
+            None => self.add_weighted_text(text, 1.0),
+        }
+    }
+
+    // Adds every section of the job description, weighted by its kind
+    pub fn add_job_description(
+        &mut self,
+        job_description: &JobDescription,
+        section_weights: &SectionWeights,
+    ) {
+        for section in job_description.get_sections() {
+            let multiplier = section_weights.get_multiplier(section.get_kind());
+            for paragraph in section.get_paragraphs() {
+                self.add_weighted_text(paragraph, multiplier);
+            }
+        }
+    }
+
+    // Adds the text with every word counting as much as the multiplier
+    pub fn add_weighted_text(&mut self, text: &str, multiplier: f32) {
+        if multiplier <= 0.0 {
+            return;
+        }
+
+        for clause in self.tokenizer.tokenize_clauses_with_words(text) {
+            let (clause, words): (Vec<String>, Vec<String>) = clause.into_iter().unzip();
+
+            for ngram in self.ngrams(&clause) {
+                let (count, frequency) = self.phrase_cloud.entry(ngram).or_insert((0, 0.0));
+                *count += 1;
+                *frequency += multiplier;
+            }
+
+            for (string, word) in clause.iter().zip(words) {
+                *self
+                    .word_forms
+                    .entry(string.clone())
+                    .or_default()
+                    .entry(word)
+                    .or_insert(0) += 1;
+            }
+
+            for string in clause {
+                match self.word_cloud.get_mut(&string) {
+                    Some(value) => {
+                        *value += multiplier;
+                    }
+                    None => {
+                        self.word_cloud.insert(string, multiplier);
+                    }
+                }
+                self.total_weight += multiplier;
+            }
+        }
+    }
+
+    // How often a word appears weighted by its inverse document frequency,
+    // 0 if it is not in the cloud
+    fn get_term_weight(&self, word: &str) -> f32 {
+        match self.word_cloud.get(word) {
+            Some(&frequency) => frequency * self.inverse_document_frequency(word),
+            None => 0.0,
+        }
+    }
+
+    // How often a phrase appears times the number of words in it, weighted by its
+    // inverse document frequency, 0 if it is not a keyword. The phrase is already
+    // tokenized and joined with spaces
+    fn get_phrase_weight(&self, phrase: &str, length: usize) -> f32 {
+        match self.phrase_cloud.get(phrase) {
+            Some(&(count, frequency))
+                if count >= MIN_NGRAM_COUNT || self.phrases.contains(phrase) =>
+            {
+                frequency * length as f32 * self.inverse_document_frequency(phrase)
+            }
+            _ => 0.0,
+        }
+    }
+
+    // Every word and keyword phrase in the cloud with its weight, heaviest first.
+    // Keywords are tokenized, use get_display_form to show them
+    pub fn get_keywords(&self) -> Vec<(String, f32)> {
+        let words = self
+            .word_cloud
+            .keys()
+            .map(|word| (word.clone(), self.get_term_weight(word)));
+        let phrases = self.phrase_cloud.keys().map(|phrase| {
+            (
+                phrase.clone(),
+                self.get_phrase_weight(phrase, phrase.split(' ').count()),
+            )
+        });
+
+        let mut keywords: Vec<(String, f32)> = words
+            .chain(phrases)
+            .filter(|(_, weight)| *weight > 0.0)
+            .collect();
+        keywords.sort_by(|a, b| a.0.cmp(&b.0));
+        keywords.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        keywords
+    }
+
+    // The keyword as it was most often written, for showing it instead of its stem.
+    // Each word of a phrase is shown on its own, ties go to the first word in name order
+    pub fn get_display_form(&self, keyword: &str) -> String {
+        keyword
+            .split(' ')
+            .map(|term| {
+                self.word_forms
+                    .get(term)
+                    .and_then(|forms| {
+                        forms
+                            .iter()
+                            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+                            .map(|(word, _)| word.as_str())
+                    })
+                    .unwrap_or(term)
+            })
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    // Every word and phrase in the text that could match a keyword, tokenized
+    // the same way as the cloud. Words inside a phrase are included on their own too
+    pub fn get_terms_in(&self, text: &str) -> HashSet<String> {
+        let mut terms = HashSet::new();
+        for clause in self.tokenizer.tokenize_clauses(text) {
+            terms.extend(self.ngrams(&clause));
+            terms.extend(clause);
+        }
+
+        terms
+    }
+
+    // Sum of the weight of every word in the cloud, the number of words
+    // in the job description when there is no corpus or section weights
+    pub fn get_total_weight(&self) -> f32 {
+        if self.document_count == 0 {
+            return self.total_weight;
+        }
+
+        sum_or_zero(
+            self.word_cloud
+                .keys()
+                .map(|word| self.get_term_weight(word)),
+        )
+    }
+
+    pub fn get_tokenizer(&self) -> &Tokenizer {
+        &self.tokenizer
     }
 
     pub fn get_word_score(&self, string: String) -> WordScore {
-        let mut score = 0;
-
-        for word in string.split(" ").collect::<Vec<&str>>() {
-            match self.word_cloud.get(&word.to_ascii_lowercase().to_string()) {
-                Some(weight) => {
-                    score += weight;
-                },
-                None => {},
+        let score = sum_or_zero(self.get_word_weights(string));
+
+        WordScore::new(score, self.get_total_weight())
+    }
+
+    // The weight of every word in the string, 0 for words that are not in the cloud.
+    // The longest keyword phrases are matched first and count as one term weighing
+    // its words plus the phrase, so a phrase match beats the same words apart
+    pub fn get_word_weights(&self, string: String) -> Vec<f32> {
+        self.get_term_weights(string)
+            .into_iter()
+            .map(|(_, weight)| weight)
+            .collect()
+    }
+
+    // Like get_word_weights, but with the word or phrase each weight is for
+    pub fn get_term_weights(&self, string: String) -> Vec<(String, f32)> {
+        let mut weights = Vec::new();
+
+        for clause in self.tokenizer.tokenize_clauses(&string) {
+            let word_weights: Vec<f32> = clause
+                .iter()
+                .map(|word| self.get_term_weight(word))
+                .collect();
+
+            let mut i = 0;
+            while i < clause.len() {
+                let phrase = (2..=self.longest_phrase.min(clause.len() - i))
+                    .rev()
+                    .map(|length| {
+                        let phrase = clause[i..i + length].join(" ");
+                        let weight = self.get_phrase_weight(&phrase, length);
+                        (length, phrase, weight)
+                    })
+                    .find(|(_, _, weight)| *weight > 0.0);
+
+                match phrase {
+                    Some((length, phrase, weight)) => {
+                        weights.push((
+                            phrase,
+                            word_weights[i..i + length].iter().sum::<f32>() + weight,
+                        ));
+                        i += length;
+                    }
+                    None => {
+                        weights.push((clause[i].clone(), word_weights[i]));
+                        i += 1;
+                    }
+                }
             }
         }
 
-        WordScore::new(score, self.total_weight)
+        weights
+    }
+}
+
+// Every file in the directory in name order, hidden files and subdirectories are skipped
+pub(crate) fn list_files(dir: String) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(&dir).map_err(|error| ResumeError::io(dir.clone(), error))?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|error| ResumeError::io(dir.clone(), error))?
+            .path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if path.is_file() && !hidden {
+            files.push(path);
+        }
     }
+    files.sort();
+
+    Ok(files)
 }
 
 impl Display for WordCloud {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Word Cloud:")?;
-        writeln!(f, "    Total Weight: {}", self.total_weight)?;
+        writeln!(f, "    Total Weight: {}", self.get_total_weight())?;
         writeln!(f, "                    Word : Weight")?;
-        for (word, weight) in self.word_cloud.iter() {
-            writeln!(f, "        {:>16} : {}", word, weight)?;
+        for word in self.word_cloud.keys() {
+            writeln!(f, "        {:>16} : {}", word, self.get_term_weight(word))?;
+        }
+        for phrase in self.phrase_cloud.keys() {
+            let weight = self.get_phrase_weight(phrase, phrase.split(' ').count());
+            if weight > 0.0 {
+                writeln!(f, "        {:>16} : {}", phrase, weight)?;
+            }
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ranking::RankingStrategy;
+    use crate::scorer::Scorer;
+    use crate::stemming::StemmingLanguage;
+
+    #[test]
+    fn ratio_of_an_empty_word_cloud_is_zero() {
+        let word_cloud = WordCloud::create_from_text(String::new());
+
+        assert_eq!(
+            word_cloud
+                .get_word_score("Rust".to_string())
+                .get_score_ratio(),
+            0.0
+        );
+        assert_eq!(RankingStrategy::Ratio.score(&word_cloud, "Rust"), 0.0);
+    }
+
+    #[test]
+    fn phrase_files_keep_hashes_inside_words() {
+        let filename = std::env::temp_dir().join("resume_builder_phrases.txt");
+        fs::write(&filename, "# languages\nC# development # for .NET jobs\n").unwrap();
+
+        let mut word_cloud = WordCloud::new();
+        word_cloud
+            .add_phrases_from_file(filename.to_string_lossy().to_string())
+            .unwrap();
+        word_cloud.add_text("We need C# development skills.");
+        fs::remove_file(&filename).unwrap();
+
+        let terms: Vec<String> = word_cloud
+            .get_term_weights("C# development".to_string())
+            .into_iter()
+            .map(|(term, _)| term)
+            .collect();
+        assert_eq!(terms, vec!["c# development"]);
+    }
+
+    #[test]
+    fn multi_word_aliases_match_their_canonical_name() {
+        let word_cloud =
+            WordCloud::create_from_text("Experience with Amazon Web Services.".to_string());
+
+        assert!(RankingStrategy::RawSum.score(&word_cloud, "AWS") > 0.0);
+    }
+
+    #[test]
+    fn words_joined_by_a_slash_match_on_their_own() {
+        let word_cloud = WordCloud::create_from_text(
+            "Experience with Python/Java and C/C++ required.".to_string(),
+        );
+
+        assert!(RankingStrategy::RawSum.score(&word_cloud, "Python") > 0.0);
+        assert!(RankingStrategy::RawSum.score(&word_cloud, "C++") > 0.0);
+    }
+
+    #[test]
+    fn stemmed_keywords_are_shown_as_words() {
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.set_stopwords(Stopwords::english());
+        tokenizer.set_stemming(Some(StemmingLanguage::English));
+        tokenizer.keep_names(["Excel"]);
+
+        let mut word_cloud = WordCloud::with_tokenizer(tokenizer);
+        word_cloud.add_text("Excellent developers. Developers who develop software.");
+
+        let keywords: Vec<String> = word_cloud
+            .get_keywords()
+            .into_iter()
+            .map(|(keyword, _)| word_cloud.get_display_form(&keyword))
+            .collect();
+        assert_eq!(keywords[0], "developers");
+        assert!(keywords.contains(&"excellent".to_string()));
+        assert!(keywords.contains(&"software".to_string()));
+        assert!(!keywords.contains(&"excel".to_string()));
+    }
+
+    #[test]
+    fn inverse_document_frequency_without_a_corpus_is_one() {
+        assert_eq!(WordCloud::new().inverse_document_frequency("rust"), 1.0);
+    }
+
+    #[test]
+    fn corpus_counts_the_documents_each_term_is_in() {
+        let dir = std::env::temp_dir().join("resume_builder_corpus");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.txt"), "Rust engineer. Rust and Kafka.").unwrap();
+        fs::write(dir.join("b.txt"), "Rust engineer for the web team.").unwrap();
+        fs::write(dir.join("c.txt"), "Rust tooling engineer.").unwrap();
+        fs::write(dir.join(".hidden.txt"), "Kafka Kafka Kafka").unwrap();
+
+        let mut word_cloud = WordCloud::new();
+        let loaded = word_cloud.load_corpus(dir.to_string_lossy().to_string());
+        fs::remove_dir_all(&dir).unwrap();
+        loaded.unwrap();
+
+        // Hidden files are skipped and a word repeated in one document counts once
+        assert_eq!(word_cloud.document_count, 3);
+        assert_eq!(word_cloud.document_frequency["rust"], 3);
+        assert_eq!(word_cloud.document_frequency["kafka"], 1);
+        assert_eq!(word_cloud.document_frequency["rust engineer"], 2);
+
+        let idf = |term: &str| word_cloud.inverse_document_frequency(term);
+        assert_eq!(idf("rust"), 1.0);
+        assert_eq!(idf("kafka"), 2.0f32.ln() + 1.0);
+        assert_eq!(idf("golang"), 4.0f32.ln() + 1.0);
+        assert!(idf("rust") < idf("kafka") && idf("kafka") < idf("golang"));
+
+        // Text added afterwards is weighted by TF-IDF
+        word_cloud.add_text("Rust and Kafka");
+        assert!(word_cloud.get_term_weight("kafka") > word_cloud.get_term_weight("rust"));
+    }
+
+    #[test]
+    fn missing_corpus_directories_are_io_errors() {
+        let dir = std::env::temp_dir().join("resume_builder_missing_corpus");
+
+        assert!(matches!(
+            WordCloud::new().load_corpus(dir.to_string_lossy().to_string()),
+            Err(ResumeError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn short_requirement_lines_are_keywords() {
+        let mut word_cloud = WordCloud::new();
+        word_cloud.add_text(
+            "Requirements\n\nExperience with Kubernetes\n\nStrong Rust skills\n\nKafka a plus\n",
+        );
+
+        let keywords: Vec<String> = word_cloud
+            .get_keywords()
+            .into_iter()
+            .map(|(keyword, _)| keyword)
+            .collect();
+        for keyword in ["kubernetes", "rust", "kafka"] {
+            assert!(keywords.contains(&keyword.to_string()));
+        }
+    }
+
+    #[test]
+    fn sections_are_weighted_by_default() {
+        let text = "About us\n\nWe build Rust tools.\n\nRequirements\n\nKafka experience\n";
+
+        let word_cloud = WordCloud::create_from_text(text.to_string());
+        assert_eq!(word_cloud.get_term_weight("kafka"), 3.0);
+        assert_eq!(word_cloud.get_term_weight("rust"), 0.0);
+
+        let mut word_cloud = WordCloud::new();
+        word_cloud.set_section_weights(None);
+        word_cloud.add_text(text);
+        assert_eq!(word_cloud.get_term_weight("kafka"), 1.0);
+        assert_eq!(word_cloud.get_term_weight("rust"), 1.0);
+    }
+}